@@ -1,15 +1,61 @@
 use std::fs;
 
+#[derive(Debug)]
+pub enum OutputFormat {
+    Kindle,
+    Dictd,
+    Epub,
+}
+
+#[derive(Debug, Clone)]
+pub enum NormalizationForm {
+    Nfc,
+    Nfkc,
+    None,
+}
+
+// how translations of the same word class from different sources are combined
+#[derive(Debug)]
+pub enum MergePolicy {
+    Union,
+    Prefer(String),
+    Common,
+}
+
+// presentation of meanings in Kindle and EPUB entries
+#[derive(Debug, Clone)]
+pub enum EntryLayout {
+    // all translations of a word class in one list followed by glosses
+    Condensed,
+    // each gloss followed by its own translations
+    Meanings,
+}
+
 #[derive(Debug)]
 pub struct CliConfig {
     pub input_file_path: Option<String>,
     pub pronunciation_files: Vec<(String, String)>,
+    pub hunspell_dictionaries: Vec<(String, String)>,
+    pub unimorph_files: Vec<(String, String)>,
     pub wiki_file_path: Option<String>,
     pub output_path: Option<String>,
+    pub output_format: OutputFormat,
     pub query: Option<String>,
+    pub dict_server: Option<String>,
+    pub http_server: Option<String>,
+    pub interactive: bool,
     pub wiki_prefix: Option<String>,
     pub source_language: String,
     pub target_language: String,
+    pub ui_language: Option<String>,
+    pub normalization_form: NormalizationForm,
+    pub fold_punctuation: bool,
+    pub fold_diacritics: bool,
+    pub merge_policy: MergePolicy,
+    pub source_licences: Vec<(String, String)>,
+    pub strict_licences: bool,
+    pub cover_image: Option<String>,
+    pub entry_layout: EntryLayout,
     pub title: String,
     pub author: String,
     pub force: bool,
@@ -24,13 +70,28 @@ impl CliConfig {
         CliConfig {
             input_file_path: None,
             pronunciation_files: Vec::new(),
+            hunspell_dictionaries: Vec::new(),
+            unimorph_files: Vec::new(),
             wiki_file_path: None,
             output_path: None,
+            output_format: OutputFormat::Kindle,
             query: None,
+            dict_server: None,
+            http_server: None,
+            interactive: false,
             wiki_prefix: None,
             force: false,
             source_language: "en".to_string(),
             target_language: "cs".to_string(),
+            ui_language: None,
+            normalization_form: NormalizationForm::Nfc,
+            fold_punctuation: true,
+            fold_diacritics: false,
+            merge_policy: MergePolicy::Union,
+            source_licences: Vec::new(),
+            strict_licences: false,
+            cover_image: None,
+            entry_layout: EntryLayout::Condensed,
             title: "".to_string(),
             author: "".to_string(),
             print_help: false,
@@ -53,14 +114,29 @@ impl CliConfig {
             match arg.as_str() {
                 "-i" => config.input_file_path = Some(Self::get_file_name(args.next())?),
                 "-o" => config.output_path = Some(Self::get_param_value(args.next())?),
+                "-of" => config.output_format = Self::get_output_format(args.next())?,
                 "-ro" => config.reverse_output_path = Some(Self::get_param_value(args.next())?),
                 "-w" => config.wiki_file_path = Some(Self::get_param_value(args.next())?),
                 "-s" => config.query = Some(Self::get_param_value(args.next())?),
+                "-ds" => config.dict_server = Some(Self::get_param_value(args.next())?),
+                "-hs" => config.http_server = Some(Self::get_param_value(args.next())?),
                 "-wp" => config.wiki_prefix = Some(Self::get_param_value(args.next())?),
                 "-p" => config.pronunciation_files.push(Self::get_pronunciation(args.next())?),
+                "-hd" => config.hunspell_dictionaries.push(Self::get_hunspell_dictionary(args.next())?),
+                "-u" => config.unimorph_files.push(Self::get_unimorph_file(args.next())?),
                 "-f" => config.force = true,
+                "-r" => config.interactive = true,
                 "-sl" => config.source_language = Self::get_param_value(args.next())?,
                 "-tl" => config.target_language = Self::get_param_value(args.next())?,
+                "-ul" => config.ui_language = Some(Self::get_param_value(args.next())?),
+                "-kn" => config.normalization_form = Self::get_normalization_form(args.next())?,
+                "-kp" => config.fold_punctuation = false,
+                "-di" => config.fold_diacritics = true,
+                "-mp" => config.merge_policy = Self::get_merge_policy(args.next())?,
+                "-li" => config.source_licences.push(Self::get_source_licence(args.next())?),
+                "-ls" => config.strict_licences = true,
+                "-ci" => config.cover_image = Some(Self::get_file_name(args.next())?),
+                "-el" => config.entry_layout = Self::get_entry_layout(args.next())?,
                 "-t" => config.title = Self::get_param_value(args.next())?,
                 "-rt" => config.reverse_title = Some(Self::get_param_value(args.next())?),
                 "-a" => config.author = Self::get_param_value(args.next())?,
@@ -90,6 +166,51 @@ impl CliConfig {
         }
     }
 
+    fn get_output_format(param: Option<String>) -> Result<OutputFormat, &'static str> {
+        match CliConfig::get_param_value(param)?.as_str() {
+            "kindle" => Ok(OutputFormat::Kindle),
+            "dictd" => Ok(OutputFormat::Dictd),
+            "epub" => Ok(OutputFormat::Epub),
+            _ => Err("Unsupported output format"),
+        }
+    }
+
+    fn get_entry_layout(param: Option<String>) -> Result<EntryLayout, &'static str> {
+        match CliConfig::get_param_value(param)?.as_str() {
+            "condensed" => Ok(EntryLayout::Condensed),
+            "meanings" => Ok(EntryLayout::Meanings),
+            _ => Err("Unsupported entry layout"),
+        }
+    }
+
+    fn get_normalization_form(param: Option<String>) -> Result<NormalizationForm, &'static str> {
+        match CliConfig::get_param_value(param)?.as_str() {
+            "nfc" => Ok(NormalizationForm::Nfc),
+            "nfkc" => Ok(NormalizationForm::Nfkc),
+            "none" => Ok(NormalizationForm::None),
+            _ => Err("Unsupported normalization form"),
+        }
+    }
+
+    fn get_merge_policy(param: Option<String>) -> Result<MergePolicy, &'static str> {
+        let policy = CliConfig::get_param_value(param)?;
+        match policy.split_once(':') {
+            Some(("prefer", source)) if !source.is_empty() => Ok(MergePolicy::Prefer(source.to_string())),
+            None if policy == "union" => Ok(MergePolicy::Union),
+            None if policy == "common" => Ok(MergePolicy::Common),
+            _ => Err("Merge policy must be union, prefer:<source> or common"),
+        }
+    }
+
+    fn get_source_licence(param: Option<String>) -> Result<(String, String), &'static str> {
+        let source_and_licence = CliConfig::get_param_value(param)?;
+        let Some((source, licence)) = source_and_licence.split_once(':') else {
+            return Err("Source licence must have 2 parts: '<source>:<licence>'");
+        };
+
+        Ok((source.trim().to_string(), licence.trim().to_string()))
+    }
+
     fn get_pronunciation(param: Option<String>) -> Result<(String, String), &'static str> {
         let name_and_file_name = CliConfig::get_param_value(param)?;
         let name_and_file_name = name_and_file_name.split(":").collect::<Vec<&str>>();
@@ -103,6 +224,29 @@ impl CliConfig {
         Ok((name, file_name))
     }
 
+    fn get_hunspell_dictionary(param: Option<String>) -> Result<(String, String), &'static str> {
+        let language_and_path = CliConfig::get_param_value(param)?;
+        let Some((language, path)) = language_and_path.split_once(':') else {
+            return Err("Hunspell dictionary must have 2 parts: '<language>:<path>'");
+        };
+
+        CliConfig::get_file_name(Some(format!("{}.aff", path)))?;
+        CliConfig::get_file_name(Some(format!("{}.dic", path)))?;
+
+        Ok((language.trim().to_string(), path.to_string()))
+    }
+
+    fn get_unimorph_file(param: Option<String>) -> Result<(String, String), &'static str> {
+        let language_and_file_name = CliConfig::get_param_value(param)?;
+        let Some((language, file_name)) = language_and_file_name.split_once(':') else {
+            return Err("UniMorph file must have 2 parts: '<language>:<filename>'");
+        };
+
+        let file_name = CliConfig::get_file_name(Some(file_name.to_string()))?;
+
+        Ok((language.trim().to_string(), file_name))
+    }
+
     pub fn print_help() {
         println!(
 r#"
@@ -122,18 +266,81 @@ Dictionary parameters:
             source language and in second column is pronunciation. You can use this
             parametr multiple times.
 
+    -hd     Hunspell dictionary with a language used to generate inflections of
+            headwords in that language instead of built-in rules e.g.
+            sk:/usr/share/hunspell/sk_SK where sk is language and the path without
+            extension points to sk_SK.aff and sk_SK.dic files. You can use this
+            parameter multiple times.
+
+    -u      UniMorph paradigm file with a language e.g. cs:data/ces where cs is
+            language and data/ces tab delimited file with lemma, inflected form and
+            features. Inflections of headwords found in the file are taken from it
+            instead of generated ones. You can use this parameter multiple times.
+
+    -mp     Merge policy for translations of a word class coming from different
+            sources: union (default) keeps all, prefer:<source> drops translations
+            of other sources if the source has any e.g. prefer:wiki, and common
+            keeps only translations found in at least two sources. Source ids are
+            gnu-fdl for -i file and wiki for -w file.
+
+    -li     Licence of a source e.g. US:MIT for pronunciation file named US. Known
+            licences are CC-BY-SA-4.0, CC-BY-SA-3.0, CC-BY-4.0, GFDL-1.1, GFDL-1.3,
+            MIT, CC0-1.0 and PD. Wiktionary is CC-BY-SA-4.0 and -i file GFDL-1.1 by
+            default. You can use this parameter multiple times.
+
+    -ls     Refuse to build a dictionary from sources with unknown or incompatible
+            licences instead of printing a warning.
+
     -o      Output directory
 
+    -of     Output format: kindle (default), dictd or epub. The dictd format creates
+            .index and .dict.dz files usable by dictd server and DICT clients.
+            The epub format creates an EPUB 3 dictionary with a search key map.
+
+    -ci     Cover image (png, jpg or gif) of Kindle or EPUB output instead of the
+            generated one showing title, languages and author. The reversed
+            dictionary always gets a generated cover.
+
+    -el     Layout of entries in Kindle and EPUB output: condensed (default) lists
+            all translations of a word class together followed by the meanings,
+            meanings shows each meaning with its own translations.
+
     -ro      Output directory for reversed dictionary
 
     -s      Search given word in builded dictionary.
 
+    -r      Interactive lookup shell over builded dictionary. Inflected forms are
+            resolved to their headwords, similar words are suggested when nothing
+            is found and prefix* lists headwords starting with the prefix.
+
+    -ds     Run DICT protocol (RFC 2229) server on given address after the dictionary
+            is built e.g. 127.0.0.1:2628. The reversed dictionary is served as well
+            if -ro is used.
+
+    -hs     Run HTTP lookup server on given address after the dictionary is built
+            e.g. 127.0.0.1:8080. Endpoints /lookup, /prefix, /inflection and /html
+            take the word in q parameter and optional database name in db parameter.
+
     -f      Force output if the output directory exists and overwrite the files.
 
     -sl     Source language. Default en.
 
     -tl     Target language. Default cs.
 
+    -ul     Language of labels in output e.g. word classes and pronunciation names.
+            Default is the target language of each dictionary. Labels are translated
+            to cs, de, es, sk and zh, English is used for other languages.
+
+    -kn     Unicode normalization of headwords and lookup keys: nfc (default), nfkc
+            or none. The nfkc form also unifies ligatures and other compatibility
+            characters e.g. ﬁ and fi.
+
+    -kp     Keep typographic apostrophes and dashes. By default ’ and – are folded
+            to ' and - so don’t and don't are the same word.
+
+    -di     Diacritic-insensitive lookup. Words are also found without diacritics
+            e.g. zena finds žena.
+
     -t      Dictionary title.
 
     -rt      Dictionary title for reversed dictionary.
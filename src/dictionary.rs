@@ -1,23 +1,47 @@
 mod language;
+mod licence;
+mod localization;
+mod normalization;
 mod reader;
-mod writer;
+pub(crate) mod writer;
 
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 
-use reader::{gnu_fdl, pronunciation, wiki};
-use writer::kindle;
+use reader::{gnu_fdl, pronunciation, unimorph, wiki};
+use writer::{dictd, epub, json, kindle};
 
-use crate::cli_config::CliConfig;
+use crate::cli_config::{CliConfig, EntryLayout, MergePolicy, OutputFormat};
 use crate::dictionary::language::LanguageProcessor;
+use crate::dictionary::licence::Source;
+use crate::dictionary::normalization::Normalization;
 
 pub struct Dictionary {
     source_language: String,
     target_language: String,
     title: String,
     author: String,
-
-    terms: HashMap<String, Term>,
+    // language of labels in output
+    ui_language: String,
+    sources: Vec<Source>,
+    // user's cover image used instead of the generated one
+    cover_image: Option<String>,
+    entry_layout: EntryLayout,
+
+    // lookup key -> homographs differing in case e.g. Polish and polish
+    terms: HashMap<String, Vec<Term>>,
+    normalization: Normalization,
+    // key without diacritics -> keys of terms, empty unless diacritic-insensitive lookup is enabled
+    folded_keys: HashMap<String, HashSet<String>>,
+    // inflected form key -> headwords having the form among their inflections
+    inflection_index: HashMap<String, HashSet<String>>,
+    // headword key -> inflected forms by word class from paradigm tables and head templates
+    paradigms: HashMap<String, HashMap<WordClass, Vec<String>>>,
+    // grammatical genders (m, f, n) of headwords and of translations
+    genders: HashMap<String, String>,
+    translation_genders: HashMap<String, String>,
+    // translation -> its inflected forms found in wiki
+    translation_forms: HashMap<String, HashSet<String>>,
     language_processor: Option<Box<dyn LanguageProcessor>>,
 }
 
@@ -42,11 +66,28 @@ pub enum WordClass {
     Preposition,
     Determiner,
     Pronoun,
-    LinkingWord,
+    Conjunction,
+    Interjection,
+    Numeral,
+    Particle,
+    ProperNoun,
+    Article,
+    Phrase,
+    Idiom,
+    Prefix,
+    Suffix,
+    Abbreviation,
     Unknown,
 }
 
 
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum SearchStrategy {
+    Exact,
+    Prefix,
+    Substring,
+}
+
 #[derive(Debug)]
 pub struct Meaning {
     order: usize,
@@ -54,7 +95,8 @@ pub struct Meaning {
     translations: TranslationType,
 }
 
-type TranslationType = HashSet<String>;
+// translation -> ids of sources it comes from
+type TranslationType = HashMap<String, HashSet<String>>;
 
 impl Dictionary {
     pub fn new(
@@ -65,7 +107,18 @@ impl Dictionary {
             target_language: target_language.to_string(),
             title: title.to_string(),
             author: author.to_string(),
+            ui_language: target_language.to_string(),
+            sources: Vec::new(),
+            cover_image: None,
+            entry_layout: EntryLayout::Condensed,
             terms: HashMap::new(),
+            normalization: Normalization::default(),
+            folded_keys: HashMap::new(),
+            inflection_index: HashMap::new(),
+            paradigms: HashMap::new(),
+            genders: HashMap::new(),
+            translation_genders: HashMap::new(),
+            translation_forms: HashMap::new(),
             language_processor: language::get_language_processor(source_language),
         }
     }
@@ -77,63 +130,266 @@ impl Dictionary {
             cfg.title.as_str(),
             cfg.author.as_str(),
         );
+        dict.normalization = Normalization::new(cfg);
+        dict.set_hunspell_processor(&cfg.hunspell_dictionaries)?;
+        dict.read_paradigms(&cfg.unimorph_files)?;
+        if let Some(ui_language) = &cfg.ui_language {
+            dict.ui_language = ui_language.clone();
+        }
+        dict.cover_image = cfg.cover_image.clone();
+        dict.entry_layout = cfg.entry_layout.clone();
 
         if let Some(path) = &cfg.input_file_path {
             match format!("{}-{}", dict.source_language, dict.target_language).as_str() {
                 "en-cs" => gnu_fdl::read_czech(&mut dict, path)?,
                 lng => Err(format!("Unsupported language combination: {}", lng))?,
             }
+            dict.sources.push(Source::new("gnu-fdl", path, &cfg.source_licences));
         }
 
         for (name, file_name) in &cfg.pronunciation_files {
             pronunciation::read_pronunciation(&mut dict, name, file_name)?;
+            dict.sources.push(Source::new(name, file_name, &cfg.source_licences));
         }
 
         if let Some(wiki_file_path) = &cfg.wiki_file_path {
             if let Some(wiki_prefix) = &cfg.wiki_prefix {
                 wiki::read_wiki(&mut dict, wiki_file_path, wiki_prefix)?;
+                dict.sources.push(Source::new("wiki", wiki_file_path, &cfg.source_licences));
             } else {
                 Err(String::from("No wiki prefix specified."))?;
             }
         }
 
+        dict.merge_sources(&cfg.merge_policy);
+        dict.check_licences(cfg.strict_licences)?;
+
         Ok(dict)
     }
 
-    pub fn reverse(&self, reversed_title: &str) -> Dictionary {
+    pub fn reverse(&self, reversed_title: &str, cfg: &CliConfig) -> Result<Dictionary, Box<dyn Error>> {
         let mut dict = Dictionary::new(
             self.target_language.as_str(),
             self.source_language.as_str(),
             reversed_title,
             self.author.as_str(),
         );
+        dict.normalization = Normalization::new(cfg);
+        dict.set_hunspell_processor(&cfg.hunspell_dictionaries)?;
+        dict.read_paradigms(&cfg.unimorph_files)?;
+        if let Some(ui_language) = &cfg.ui_language {
+            dict.ui_language = ui_language.clone();
+        }
+        dict.entry_layout = cfg.entry_layout.clone();
+        dict.genders = self.translation_genders.clone();
+        dict.sources = self.sources.clone();
 
-        for (_headword_key, term) in self.terms.iter() {
+        for term in self.terms.values().flatten() {
             for (word_class, meanings) in term.classes.iter() {
                 for (_, meaning) in meanings.iter() {
-                    for translation in meaning.translations.iter() {
+                    for (translation, sources) in meaning.translations.iter() {
                         let mut m = Meaning::new(meaning.description.as_str());
-                        m.add_translation(&term.headword);
+                        for source in sources {
+                            m.add_translation(&term.headword, source);
+                        }
                         dict.add_meaning(translation, word_class, &m);
                     }
                 }
             }
         }
 
-        dict
+        for (translation, forms) in self.translation_forms.iter() {
+            dict.add_inflections(translation, forms);
+        }
+
+        Ok(dict)
+    }
+
+    // keep translations of each word class according to the sources they come from
+    fn merge_sources(&mut self, policy: &MergePolicy) {
+        if let MergePolicy::Union = policy {
+            return;
+        }
+
+        for term in self.terms.values_mut().flatten() {
+            for meanings in term.classes.values_mut() {
+                let mut sources = HashMap::<String, HashSet<String>>::new();
+                for meaning in meanings.values() {
+                    for (translation, translation_sources) in meaning.translations.iter() {
+                        sources.entry(translation.clone()).or_default().extend(translation_sources.iter().cloned());
+                    }
+                }
+                let keep = |translation: &String| match policy {
+                    MergePolicy::Union => true,
+                    MergePolicy::Prefer(source) => {
+                        sources[translation].contains(source) || !sources.values().any(|s| s.contains(source))
+                    },
+                    MergePolicy::Common => sources[translation].len() > 1,
+                };
+                for meaning in meanings.values_mut() {
+                    meaning.translations.retain(|translation, _| keep(translation));
+                }
+                meanings.retain(|_, meaning| !meaning.is_empty());
+            }
+            term.classes.retain(|_, meanings| !meanings.is_empty());
+        }
+    }
+
+    fn check_licences(&self, strict: bool) -> Result<(), Box<dyn Error>> {
+        let conflicts = licence::conflicts(&self.used_sources());
+        if strict && !conflicts.is_empty() {
+            Err(conflicts.join("\n"))?;
+        }
+        for conflict in conflicts {
+            eprintln!("Warning: {}", conflict);
+        }
+        Ok(())
+    }
+
+    // sources with at least one translation or pronunciation in the dictionary
+    pub fn used_sources(&self) -> Vec<&Source> {
+        let statistics = self.source_statistics();
+        self.sources
+            .iter()
+            .filter(|source| statistics.iter().any(|(id, translations, pronunciations)| {
+                *id == source.id && translations + pronunciations > 0
+            }))
+            .collect()
+    }
+
+    fn set_hunspell_processor(&mut self, hunspell_dictionaries: &[(String, String)]) -> Result<(), Box<dyn Error>> {
+        if let Some(processor) = language::get_hunspell_processor(&self.source_language, hunspell_dictionaries)? {
+            self.language_processor = Some(processor);
+        }
+        Ok(())
+    }
+
+    fn read_paradigms(&mut self, unimorph_files: &[(String, String)]) -> Result<(), Box<dyn Error>> {
+        for (language, file_name) in unimorph_files {
+            if *language == self.source_language {
+                unimorph::read_unimorph(self, file_name)?;
+            }
+        }
+        Ok(())
+    }
+
+    // known forms of the lemma, even empty paradigm stops generating inflections
+    pub fn add_paradigm(&mut self, lemma: &str, word_class: &WordClass, forms: &[String]) {
+        let key = self.key(lemma);
+        self.paradigms
+            .entry(key.clone())
+            .or_default()
+            .entry(word_class.clone())
+            .or_default()
+            .extend(forms.iter().cloned());
+
+        // terms added before the paradigm was known have generated forms to replace
+        let rebuilt = self.terms
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .filter(|term| term.classes.contains_key(word_class))
+            .map(|term| {
+                let inflections = term.classes
+                    .keys()
+                    .flat_map(|class| self.class_inflections(&term.headword, class))
+                    .collect::<HashSet<_>>();
+                (term.headword.clone(), inflections)
+            })
+            .collect::<Vec<_>>();
+
+        for (headword, inflections) in rebuilt {
+            let Some(term) = self.terms
+                .get_mut(&key)
+                .and_then(|homographs| homographs.iter_mut().find(|term| term.headword == headword)) else {
+                continue;
+            };
+            for form in term.inflections.iter() {
+                let form_key = self.normalization.key(form);
+                if let Some(headwords) = self.inflection_index.get_mut(&form_key) {
+                    headwords.remove(&headword);
+                    if headwords.is_empty() {
+                        self.inflection_index.remove(&form_key);
+                    }
+                }
+            }
+            for form in inflections.iter() {
+                self.inflection_index
+                    .entry(self.normalization.key(form))
+                    .or_default()
+                    .insert(headword.clone());
+            }
+            term.inflections = inflections;
+        }
+    }
+
+    // real paradigms are preferred over generated inflections
+    fn class_inflections(&self, headword: &str, word_class: &WordClass) -> Vec<String> {
+        let key = self.key(headword);
+        let paradigm = self.paradigms.get(&key).and_then(|classes| classes.get(word_class));
+        match (paradigm, &self.language_processor) {
+            (Some(forms), _) => forms.clone(),
+            (None, Some(language_processor)) => {
+                let gender = self.genders.get(&key).map(|gender| gender.as_str());
+                language_processor.inflect_with_gender(headword, word_class, gender)
+            },
+            (None, None) => vec![],
+        }
     }
 
+    // pronunciation lists often differ in case from headwords, so without a homograph
+    // of the same case the pronunciation is shared by all homographs
     pub fn add_pronunciation(&mut self, headword: &str, name: &str, pronunciation: &str) {
-        let entry = self.terms.entry(Self::word_to_key(headword)).or_insert(Term::new(headword));
-        let pron_entry = entry.pronunciations.entry(name.to_string()).or_default();
-        pron_entry.push(pronunciation.to_string());
+        let headword = &self.normalization.normalize(headword);
+        let key = self.key(headword);
+        self.add_folded_key(&key);
+        let homographs = self.terms.entry(key).or_default();
+        let entries = match homographs.iter().position(|term| term.headword == *headword) {
+            Some(index) => &mut homographs[index..=index],
+            None if homographs.is_empty() => {
+                homographs.push(Term::new(headword));
+                &mut homographs[..]
+            },
+            None => &mut homographs[..],
+        };
+        for entry in entries {
+            let pron_entry = entry.pronunciations.entry(name.to_string()).or_default();
+            pron_entry.push(pronunciation.to_string());
+        }
+    }
+
+    pub fn add_translation_form(&mut self, translation: &str, form: &str) {
+        self.translation_forms
+            .entry(self.normalization.normalize(translation))
+            .or_default()
+            .insert(form.to_string());
+    }
+
+    // known forms of an existing headword added to the generated ones
+    fn add_inflections(&mut self, headword: &str, forms: &HashSet<String>) {
+        let Some(term) = self.terms
+            .get_mut(&self.normalization.key(headword))
+            .and_then(|homographs| homographs.iter_mut().find(|term| term.headword == headword)) else {
+            return;
+        };
+
+        for form in forms.iter().filter(|form| **form != term.headword) {
+            self.inflection_index
+                .entry(self.normalization.key(form))
+                .or_default()
+                .insert(headword.to_string());
+            term.inflections.insert(form.clone());
+        }
+    }
+
+    pub fn add_translation_gender(&mut self, translation: &str, gender: &str) {
+        self.translation_genders.insert(self.key(translation), gender.to_string());
     }
 
     pub fn get_meaning_position(&self, headword: &str, word_class: &WordClass, meaning: &Meaning) -> usize {
-        let key = Self::word_to_key(headword);
-        if let Some(term) = self.terms.get(&key) {
+        if let Some(term) = self.term(headword) {
             if let Some(class) = term.classes.get(word_class) {
-                let key = Self::word_to_key(&meaning.description);
+                let key = self.key(&meaning.description);
                 if let Some(m) = class.get(&key) {
                     return m.order;
                 } else {
@@ -146,44 +402,232 @@ impl Dictionary {
     }
 
     pub fn add_meaning(&mut self, headword: &str, word_class: &WordClass, meaning: &Meaning) {
+        let headword = &self.normalization.normalize(headword);
         let order = self.get_meaning_position(headword, word_class, meaning);
 
-        let entry = self.terms
-            .entry(Self::word_to_key(headword))
-            .or_insert(Term::new(headword));
-
-        if let Some(language_processor) = &self.language_processor {
-            let inflections = language_processor.inflect(headword, word_class);
-            entry.inflections.extend(inflections);
+        let headword_key = self.key(headword);
+        self.add_folded_key(&headword_key);
+        let inflections = self.class_inflections(headword, word_class);
+        let homographs = self.terms.entry(headword_key.clone()).or_default();
+        let entry = match homographs.iter().position(|term| term.headword == *headword) {
+            Some(index) => &mut homographs[index],
+            None => {
+                homographs.push(Term::new(headword));
+                homographs.last_mut().unwrap()
+            },
+        };
+
+        for inflection in inflections.iter() {
+            self.inflection_index
+                .entry(self.normalization.key(inflection))
+                .or_default()
+                .insert(headword.to_string());
         }
+        entry.inflections.extend(inflections);
 
         let class_entry = entry.classes
             .entry(word_class.clone())
             .or_default();
 
         let meaning_entry = class_entry
-            .entry(Self::word_to_key(meaning.description.as_str()))
+            .entry(self.normalization.key(meaning.description.as_str()))
             .or_insert(Meaning::new(meaning.description.as_str()));
 
-        meaning_entry.translations.extend(meaning.translations.clone());
+        for (translation, sources) in meaning.translations.iter() {
+            meaning_entry.translations
+                .entry(translation.clone())
+                .or_default()
+                .extend(sources.iter().cloned());
+        }
         meaning_entry.order = order;
     }
 
 
-    pub fn lookup(&self, word: &str) -> Option<&Term> {
-        self.terms.get(Self::word_to_key(word).as_str())
+    // all homographs of the word, the one written the same way first
+    pub fn lookup(&self, word: &str) -> Vec<&Term> {
+        let word = self.normalization.normalize(word);
+        let mut terms = self.lookup_keys(&word)
+            .iter()
+            .filter_map(|key| self.terms.get(key))
+            .flatten()
+            .collect::<Vec<_>>();
+        terms.sort_by_key(|term| (term.headword != word, term.headword.clone()));
+        terms
+    }
+
+    // key of the word and keys differing only in diacritics if enabled
+    fn lookup_keys(&self, word: &str) -> Vec<String> {
+        let key = self.key(word);
+        let mut keys = self.normalization
+            .folded_key(&key)
+            .and_then(|folded| self.folded_keys.get(&folded))
+            .into_iter()
+            .flatten()
+            .filter(|folded| **folded != key)
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.insert(0, key);
+        keys
+    }
+
+    fn add_folded_key(&mut self, key: &str) {
+        if let Some(folded) = self.normalization.folded_key(key) {
+            self.folded_keys.entry(folded).or_default().insert(key.to_string());
+        }
+    }
+
+    // homograph written exactly as the headword
+    fn term(&self, headword: &str) -> Option<&Term> {
+        self.homographs(headword).iter().find(|term| term.headword == headword)
+    }
+
+    fn homographs(&self, word: &str) -> &[Term] {
+        self.terms.get(&self.key(word)).map_or(&[], |terms| terms.as_slice())
+    }
+
+    // all terms including homographs sharing a key
+    fn all_terms(&self) -> impl Iterator<Item = &Term> {
+        self.terms.values().flatten()
+    }
+
+    // find all headwords having the word among their inflections or being its lemma
+    pub fn lookup_inflection(&self, word: &str) -> Vec<&Term> {
+        let mut terms = self.inflection_index
+            .get(&self.key(word))
+            .into_iter()
+            .flatten()
+            .filter_map(|headword| self.term(headword))
+            .collect::<Vec<_>>();
+
+        for term in self.lemmatize(word) {
+            if !terms.iter().any(|t| std::ptr::eq(*t, term)) {
+                terms.push(term);
+            }
+        }
+
+        terms.sort_by(|a, b| a.headword.cmp(&b.headword));
+        terms
+    }
+
+    // headwords found by the language processor as lemmas of the word with matching word class
+    pub fn lemmatize(&self, word: &str) -> Vec<&Term> {
+        let Some(language_processor) = &self.language_processor else {
+            return Vec::new();
+        };
+
+        let mut terms: Vec<&Term> = vec![];
+        for (lemma, word_class) in language_processor.lemmatize(word) {
+            for term in self.homographs(&lemma) {
+                if term.classes.contains_key(&word_class) && !terms.iter().any(|t| std::ptr::eq(*t, term)) {
+                    terms.push(term);
+                }
+            }
+        }
+        terms
+    }
+
+    // the term itself followed by headwords the word is an inflected form of
+    pub fn resolve(&self, word: &str) -> Vec<&Term> {
+        let mut terms = self.search(word, SearchStrategy::Exact);
+        for term in self.lookup_inflection(word) {
+            if !terms.iter().any(|t| std::ptr::eq(*t, term)) {
+                terms.push(term);
+            }
+        }
+        terms
+    }
+
+    // find all non-empty terms matching the word by given strategy sorted by headword
+    pub fn search(&self, word: &str, strategy: SearchStrategy) -> Vec<&Term> {
+        let key = self.key(word);
+        let exact_keys = self.lookup_keys(word);
+        // prefix and substring are also matched without diacritics if enabled
+        let folded = self.normalization.folded_key(&key);
+        let fold = |term_key: &str| self.normalization.folded_key(term_key).unwrap_or_default();
+        let mut terms = self.terms
+            .iter()
+            .filter(|(term_key, _)| match strategy {
+                SearchStrategy::Exact => exact_keys.contains(term_key),
+                SearchStrategy::Prefix => term_key.starts_with(&key)
+                    || folded.as_ref().is_some_and(|folded| fold(term_key).starts_with(folded)),
+                SearchStrategy::Substring => term_key.contains(&key)
+                    || folded.as_ref().is_some_and(|folded| fold(term_key).contains(folded)),
+            })
+            .flat_map(|(_, terms)| terms)
+            .filter(|term| !term.is_empty())
+            .collect::<Vec<_>>();
+        terms.sort_by(|a, b| a.headword.cmp(&b.headword));
+        terms
+    }
+
+    // terms with headwords similar to the word ordered by edit distance
+    pub fn suggest(&self, word: &str, max_distance: usize, limit: usize) -> Vec<&Term> {
+        let key = self.key(word).chars().collect::<Vec<_>>();
+        let mut suggestions = self.terms
+            .iter()
+            .filter(|(term_key, _)| term_key.chars().count().abs_diff(key.len()) <= max_distance)
+            .filter_map(|(term_key, terms)| {
+                let distance = levenshtein(&key, &term_key.chars().collect::<Vec<_>>());
+                if distance <= max_distance { Some((distance, terms)) } else { None }
+            })
+            .flat_map(|(distance, terms)| terms.iter().map(move |term| (distance, term)))
+            .filter(|(_, term)| !term.is_empty())
+            .collect::<Vec<_>>();
+        suggestions.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.headword.cmp(&b.1.headword)));
+        suggestions.into_iter().take(limit).map(|(_, term)| term).collect()
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn ui_language(&self) -> &str {
+        &self.ui_language
+    }
+
+    pub fn entry_layout(&self) -> &EntryLayout {
+        &self.entry_layout
+    }
+
+    pub fn name(&self) -> String {
+        dictd::database_name(self)
+    }
+
+    pub fn info(&self) -> String {
+        dictd::database_info(self)
     }
 
     pub fn len(&self) -> usize {
-        self.terms.len()
+        self.all_terms().count()
     }
     
     pub fn non_empty_len(&self) -> usize {
-        self.terms.iter().filter(|(_, term)| !term.is_empty()).count()
+        self.all_terms().filter(|term| !term.is_empty()).count()
     }
  
+    // number of translations and pronunciations by source id
+    pub fn source_statistics(&self) -> Vec<(String, usize, usize)> {
+        let mut statistics = HashMap::<&str, (usize, usize)>::new();
+        for term in self.all_terms() {
+            for meaning in term.classes.values().flat_map(|meanings| meanings.values()) {
+                for source in meaning.translations.values().flatten() {
+                    statistics.entry(source).or_default().0 += 1;
+                }
+            }
+            for (source, pronunciations) in term.pronunciations.iter() {
+                statistics.entry(source).or_default().1 += pronunciations.len();
+            }
+        }
+        let mut statistics = statistics
+            .into_iter()
+            .map(|(source, (translations, pronunciations))| (source.to_string(), translations, pronunciations))
+            .collect::<Vec<_>>();
+        statistics.sort();
+        statistics
+    }
+
     pub fn translations_len(&self) -> usize {
-        self.terms.iter().filter(|(_, term)| 
+        self.all_terms().filter(|term| 
             term.classes.iter().filter(|(_, meaning)| 
                 meaning.iter().filter(|(_, m)| 
                     !m.translations.is_empty()
@@ -198,15 +642,50 @@ impl Dictionary {
     }
     */
 
+    pub fn write(&self, output_path: &str, output_format: &OutputFormat, force: bool) -> Result<(), Box<dyn Error>> {
+        match output_format {
+            OutputFormat::Kindle => self.to_kindle(output_path, force),
+            OutputFormat::Dictd => self.to_dictd(output_path, force),
+            OutputFormat::Epub => self.to_epub(output_path, force),
+        }
+    }
+
     pub fn to_kindle(&self, output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
         kindle::to_kindle(self, output_path, force)
     }
 
+    pub fn to_dictd(&self, output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
+        dictd::to_dictd(self, output_path, force)
+    }
+
+    pub fn to_epub(&self, output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
+        epub::to_epub(self, output_path, force)
+    }
+
+    // lookup key of the word in this dictionary
+    pub fn key(&self, word: &str) -> String {
+        self.normalization.key(word)
+    }
+
     pub fn word_to_key(word: &str) -> String {
         word.to_lowercase()
     }
 }
 
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            current[j + 1] = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
 impl Term {
     pub fn new(headword: &str) -> Term {
         Term {
@@ -217,6 +696,22 @@ impl Term {
         }
     }
 
+    pub fn headword(&self) -> &str {
+        &self.headword
+    }
+
+    pub fn to_plain_text(&self, language: &str) -> String {
+        dictd::format_term(self, language)
+    }
+
+    pub fn to_json(&self) -> String {
+        json::format_term(self)
+    }
+
+    pub fn to_kindle_html(&self, language: &str, layout: &EntryLayout) -> String {
+        kindle::format_entry(self, language, layout)
+    }
+
     pub fn is_empty(&self) -> bool {
         if self.headword.is_empty() {
             return true;
@@ -238,9 +733,19 @@ impl WordClass {
             WordClass::Adjective => "adjective",
             WordClass::Adverb => "adverb",
             WordClass::Determiner => "determiner",
-            WordClass::LinkingWord => "linking",
             WordClass::Preposition => "preposition",
             WordClass::Pronoun => "pronoun",
+            WordClass::Conjunction => "conjunction",
+            WordClass::Interjection => "interjection",
+            WordClass::Numeral => "numeral",
+            WordClass::Particle => "particle",
+            WordClass::ProperNoun => "proper noun",
+            WordClass::Article => "article",
+            WordClass::Phrase => "phrase",
+            WordClass::Idiom => "idiom",
+            WordClass::Prefix => "prefix",
+            WordClass::Suffix => "suffix",
+            WordClass::Abbreviation => "abbreviation",
             WordClass::Unknown => "other",
         }
     }
@@ -255,11 +760,46 @@ impl Meaning {
         }
     }
 
-    pub fn add_translation(&mut self, translation: &str) {
-        self.translations.insert(translation.to_string());
+    pub fn add_translation(&mut self, translation: &str, source: &str) {
+        self.translations
+            .entry(translation.to_string())
+            .or_default()
+            .insert(source.to_string());
     }
 
     pub fn is_empty(&self) -> bool {
         self.description.is_empty() && self.translations.is_empty()
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paradigm_replaces_generated_forms() {
+        let mut dict = Dictionary::new("en", "cs", "test", "test");
+        dict.add_meaning("child", &WordClass::Noun, &Meaning::new("young person"));
+        dict.add_meaning("child", &WordClass::Verb, &Meaning::new("give birth"));
+        assert!(dict.inflection_index["children"].contains("child"));
+
+        dict.add_paradigm("child", &WordClass::Noun, &["kids".to_string()]);
+        let term = dict.term("child").unwrap();
+        assert!(term.inflections.contains("kids"));
+        assert!(!term.inflections.contains("children"));
+        assert!(!dict.inflection_index.contains_key("children"));
+        assert!(dict.inflection_index["kids"].contains("child"));
+        // forms of other word classes stay
+        assert!(term.inflections.contains("childed"));
+        assert!(dict.inflection_index["childed"].contains("child"));
+    }
+
+    #[test]
+    fn paradigm_before_meaning() {
+        let mut dict = Dictionary::new("en", "cs", "test", "test");
+        dict.add_paradigm("child", &WordClass::Noun, &["kids".to_string()]);
+        dict.add_meaning("child", &WordClass::Noun, &Meaning::new("young person"));
+        assert_eq!(dict.term("child").unwrap().inflections, HashSet::from(["kids".to_string()]));
+    }
+}
@@ -1,18 +1,48 @@
 use std::collections::HashMap;
+use std::error::Error;
 
 use crate::dictionary::WordClass;
 
+pub mod cs;
+pub mod de;
 pub mod en;
+pub mod es;
+pub mod hunspell;
 
 pub type IrregularVerbType = HashMap<String, Vec<String>>;
+pub type IrregularNounType = HashMap<String, Vec<String>>;
+pub type IrregularAdjectiveType = HashMap<String, Vec<String>>;
 
-pub trait LanguageProcessor {
+pub trait LanguageProcessor: Send + Sync {
     fn inflect(&self, headword: &str, word_class: &WordClass) -> Vec<String>;
+
+    // languages declining nouns by gender can make use of it when it is known
+    fn inflect_with_gender(&self, headword: &str, word_class: &WordClass, _gender: Option<&str>) -> Vec<String> {
+        self.inflect(headword, word_class)
+    }
+
+    // candidate lemmas with word classes the word is an inflected form of, the inverse of inflect
+    fn lemmatize(&self, _word: &str) -> Vec<(String, WordClass)> {
+        vec![]
+    }
 }
 
 pub fn get_language_processor(source_language: &str) -> Option<Box<dyn LanguageProcessor>> {
     match source_language {
+        "cs" => Some(Box::new(cs::Czech::new())),
+        "de" => Some(Box::new(de::German::new())),
         "en" => Some(Box::new(en::English::new())),
+        "es" => Some(Box::new(es::Spanish::new())),
         _ => None
     }
 }
+
+// Hunspell dictionary given for the language takes precedence over built-in processor
+pub fn get_hunspell_processor(
+    language: &str, hunspell_dictionaries: &[(String, String)],
+) -> Result<Option<Box<dyn LanguageProcessor>>, Box<dyn Error>> {
+    match hunspell_dictionaries.iter().find(|(lang, _)| lang == language) {
+        Some((_, path)) => Ok(Some(Box::new(hunspell::Hunspell::load(path)?))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,402 @@
+mod irregular_nouns;
+mod irregular_verbs;
+
+use crate::dictionary::{language::{IrregularNounType, IrregularVerbType, LanguageProcessor}, Dictionary, WordClass};
+
+const CONSONANTS: &str = "bcčdďfghjklmnňpqrřsštťvwxzž";
+const SOFT_CONSONANTS: &str = "cčďjňřšťž";
+
+// prefixes irregular verbs take in derived verbs e.g. přijít -> jít, other verbs only look alike: dbát is not d + bát
+const DERIVED_VERBS: [(&str, &[&str]); 27] = [
+    ("jít", &["do", "na", "nade", "obe", "ode", "po", "pode", "pro", "pře", "přede", "při", "roze", "se", "u", "ve", "vy", "za"]),
+    ("jet", &["do", "na", "ob", "od", "po", "pro", "pře", "před", "při", "roz", "s", "u", "v", "vy", "za"]),
+    ("vědět", &["do", "po", "z"]),
+    ("chtít", &["za"]),
+    ("jíst", &["do", "na", "po", "pře", "u", "vy"]),
+    ("moci", &["po", "pře", "z"]),
+    ("moct", &["po", "pře", "z"]),
+    ("péci", &["do", "na", "pře", "u", "vy", "za"]),
+    ("péct", &["do", "na", "pře", "u", "vy", "za"]),
+    ("říci", &["od", "vy", "za"]),
+    ("říct", &["od", "vy", "za"]),
+    ("číst", &["do", "ode", "po", "pro", "pře", "při", "se", "vy", "za"]),
+    ("stát", &["ob", "pře", "u"]),
+    ("hrát", &["do", "na", "ode", "po", "pro", "pře", "se", "vy", "za"]),
+    ("pít", &["do", "na", "o", "po", "pro", "u", "vy", "z", "za"]),
+    ("žít", &["do", "o", "pro", "pře", "u", "vy", "za"]),
+    ("bít", &["do", "na", "od", "po", "pro", "při", "roz", "u", "vy", "z", "za"]),
+    ("krýt", &["od", "po", "pře", "při", "s", "u", "za"]),
+    ("mýt", &["o", "s", "u", "vy"]),
+    ("vést", &["do", "na", "od", "po", "pro", "pře", "při", "roz", "s", "u", "vy", "za"]),
+    ("nést", &["do", "na", "od", "po", "pro", "pře", "při", "roz", "s", "u", "vy", "vz", "za"]),
+    ("vézt", &["do", "na", "od", "pře", "při", "roz", "s", "vy", "za"]),
+    ("růst", &["do", "na", "pře", "pro", "vy", "vz", "za"]),
+    ("vzít", &["pře"]),
+    ("smát", &["na", "po", "u", "vy", "za"]),
+    ("přát", &["do", "po"]),
+    ("kázat", &["do", "na", "od", "pro", "při", "roz", "u", "vy", "za"]),
+];
+
+pub struct Czech {
+    irregular_nouns: IrregularNounType,
+    irregular_verbs: IrregularVerbType,
+}
+
+impl Czech {
+    pub fn new() -> Czech {
+        Czech {
+            irregular_nouns: irregular_nouns::build(),
+            irregular_verbs: irregular_verbs::build(),
+        }
+    }
+
+    fn decline_noun(&self, inflections: &mut Vec<String>, headword: &str) {
+        if let Some(forms) = self.irregular_nouns.get(&Dictionary::word_to_key(headword)) {
+            inflections.extend(forms.iter().cloned());
+            return;
+        }
+
+        if headword.ends_with("ost") {
+            // feminine i-stems: radost, kost
+            add_endings(inflections, headword, &["i", "í", "em", "ech", "mi"]);
+        } else if let Some(stem) = headword.strip_suffix('a') {
+            // feminine hard: žena
+            add_endings(inflections, stem, &["y", "u", "o", "ou", "ám", "ách", "ami"]);
+            inflections.push(soften_e(stem));
+            inflections.extend(genitive_plural(stem));
+        } else if let Some(stem) = headword.strip_suffix('e').or_else(|| headword.strip_suffix('ě')) {
+            // feminine soft: růže, země; neuter soft: moře; masculine: soudce
+            let e = if headword.ends_with('ě') { "ě" } else { "e" };
+            add_endings(inflections, stem, &["i", "í", "ím", "ích", "em"]);
+            inflections.push(format!("{}{}mi", stem, e));
+            if stem.ends_with('c') {
+                add_endings(inflections, stem, &["", "ů", "ům", "ové"]);
+            }
+        } else if let Some(stem) = headword.strip_suffix('o') {
+            // neuter hard: město
+            add_endings(inflections, stem, &["a", "u", "em", "y", "ům", "ech"]);
+            if !is_velar(stem) {
+                inflections.push(soften_e(stem));
+            }
+            inflections.extend(genitive_plural(stem));
+        } else if let Some(stem) = headword.strip_suffix('í') {
+            // neuter: stavení
+            add_endings(inflections, stem, &["ím", "ích", "ími"]);
+        } else if headword.ends_with("tel") || ends_with_any(headword, SOFT_CONSONANTS) {
+            // masculine soft: muž, stroj, učitel, chlapec
+            let stem = mobile_e(headword);
+            add_endings(inflections, &stem, &["e", "i", "ovi", "em", "ové", "é", "ů", "ům", "ích"]);
+        } else if ends_with_any(headword, CONSONANTS) {
+            // masculine hard: pán, hrad, domek
+            let stem = mobile_e(headword);
+            add_endings(inflections, &stem, &["a", "u", "ovi", "em", "e", "ové", "y", "ů", "ům"]);
+            if is_velar(&stem) {
+                inflections.push(format!("{}i", palatalize(&stem)));
+                inflections.push(format!("{}ích", palatalize(&stem)));
+            } else {
+                inflections.push(soften_e(&stem));
+                inflections.push(format!("{}i", palatalize(&stem)));
+                inflections.push(format!("{}ech", stem));
+            }
+        }
+    }
+
+    fn decline_adjective(&self, inflections: &mut Vec<String>, headword: &str) {
+        if let Some(stem) = headword.strip_suffix('ý') {
+            // hard: mladý
+            add_endings(inflections, stem, &["á", "é", "ého", "ému", "ém", "ým", "ou", "ých", "ými"]);
+            inflections.push(format!("{}í", palatalize(stem)));
+        } else if let Some(stem) = headword.strip_suffix('í') {
+            // soft: jarní
+            add_endings(inflections, stem, &["ího", "ímu", "ím", "ích", "ími"]);
+        }
+    }
+
+    fn conjugate_verb(&self, inflections: &mut Vec<String>, headword: &str) {
+        if self.add_irregular_verb(inflections, headword) {
+            return;
+        }
+
+        if let Some(stem) = headword.strip_suffix("ovat") {
+            add_endings(inflections, stem, &[
+                "uji", "uju", "uješ", "uje", "ujeme", "ujete", "ují", "ujou",
+                "oval", "ovala", "ovalo", "ovali", "ovaly",
+                "uj", "ujme", "ujte", "ován", "ována", "ováno", "ováni", "ovány", "ovaný", "ující",
+            ]);
+        } else if let Some(stem) = headword.strip_suffix("nout") {
+            add_endings(inflections, stem, &["nu", "neš", "ne", "neme", "nete", "nou", "nul", "nula", "nulo", "nuli", "nuly", "nut", "nutý"]);
+            if ends_with_any(stem, CONSONANTS) {
+                add_endings(inflections, stem, &["l", "la", "lo", "li", "ly", "ni", "něme", "něte"]);
+            } else {
+                add_endings(inflections, stem, &["ň", "ňme", "ňte"]);
+            }
+        } else if let Some(stem) = headword.strip_suffix("at").or_else(|| headword.strip_suffix("át")) {
+            let a = if headword.ends_with("át") { "á" } else { "a" };
+            add_endings(inflections, stem, &["ám", "áš", "á", "áme", "áte", "ají", "ej", "ejme", "ejte", "án", "ána", "áno", "áni", "ány", "aný", "ající"]);
+            for ending in ["l", "la", "lo", "li", "ly"] {
+                inflections.push(format!("{}{}{}", stem, a, ending));
+            }
+        } else if let Some(stem) = headword.strip_suffix("it").or_else(|| headword.strip_suffix("ít")) {
+            add_endings(inflections, stem, &["ím", "íš", "í", "íme", "íte", "il", "ila", "ilo", "ili", "ily", "ící"]);
+            let imperative = imperative(stem);
+            inflections.push(imperative.clone());
+            if let Some(base) = imperative.strip_suffix('i') {
+                add_endings(inflections, base, &["eme", "ete"]);
+            } else {
+                add_endings(inflections, &imperative, &["me", "te"]);
+            }
+            let passive = passive(stem);
+            add_endings(inflections, &passive, &["", "a", "o", "i", "y", "ý"]);
+        } else if let Some(stem) = headword.strip_suffix("et").or_else(|| headword.strip_suffix("ět")) {
+            let e = if headword.ends_with("ět") { "ě" } else { "e" };
+            add_endings(inflections, stem, &["ím", "íš", "í", "íme", "íte", "ící"]);
+            for ending in ["jí", "l", "la", "lo", "li", "ly", "j", "jme", "jte"] {
+                inflections.push(format!("{}{}{}", stem, e, ending));
+            }
+        }
+    }
+
+    // irregular verb itself or derived from irregular verb by a prefix it takes
+    fn add_irregular_verb(&self, inflections: &mut Vec<String>, headword: &str) -> bool {
+        let key = Dictionary::word_to_key(headword);
+        if let Some(forms) = self.irregular_verbs.get(&key) {
+            inflections.extend(forms.iter().cloned());
+            return true;
+        }
+
+        for (verb, prefixes) in DERIVED_VERBS {
+            let Some(prefix) = key.strip_suffix(verb).filter(|prefix| prefixes.contains(prefix)) else {
+                continue;
+            };
+            let Some(forms) = self.irregular_verbs.get(verb) else {
+                continue;
+            };
+            // future with po-/pů- takes no other prefix: přijdu, not připůjdu
+            let forms = forms.iter().filter(|form| !form.starts_with("po") && !form.starts_with("pů"));
+            inflections.extend(forms.map(|form| format!("{}{}", prefix, form)));
+            return true;
+        }
+
+        false
+    }
+}
+
+impl LanguageProcessor for Czech {
+    fn inflect(&self, headword: &str, word_class: &WordClass) -> Vec<String> {
+        let mut inflections = vec![];
+
+        if let Some((word, rest)) = headword.split_once(' ') {
+            // reflexive and other multi word verbs: smát se, mít rád
+            if *word_class == WordClass::Verb && !self.add_irregular_verb(&mut inflections, headword) {
+                let mut forms = vec![];
+                self.conjugate_verb(&mut forms, word);
+                inflections.extend(forms.iter().filter(|form| !form.is_empty()).map(|form| format!("{} {}", form, rest)));
+            }
+        } else {
+            match word_class {
+                WordClass::Noun => self.decline_noun(&mut inflections, headword),
+                WordClass::Adjective => self.decline_adjective(&mut inflections, headword),
+                WordClass::Verb => self.conjugate_verb(&mut inflections, headword),
+                _ => (),
+            }
+        }
+
+        inflections.retain(|inflection| !inflection.is_empty() && inflection != headword);
+        inflections.sort();
+        inflections.dedup();
+        inflections
+    }
+}
+
+impl Default for Czech {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn add_endings(inflections: &mut Vec<String>, stem: &str, endings: &[&str]) {
+    for ending in endings {
+        inflections.push(format!("{}{}", stem, ending));
+    }
+}
+
+fn ends_with_any(word: &str, chars: &str) -> bool {
+    word.chars().last().is_some_and(|c| chars.contains(c))
+}
+
+fn is_velar(stem: &str) -> bool {
+    stem.ends_with("ch") || ends_with_any(stem, "kgh")
+}
+
+// dative and locative -e ending softening the stem: ruka -> ruce, sestra -> sestře, žena -> ženě
+fn soften_e(stem: &str) -> String {
+    let replacements = [("ch", "še"), ("k", "ce"), ("h", "ze"), ("g", "ze"), ("r", "ře")];
+    for (ending, replacement) in replacements {
+        if let Some(base) = stem.strip_suffix(ending) {
+            return format!("{}{}", base, replacement);
+        }
+    }
+
+    if ends_with_any(stem, "dtnbpvfm") {
+        format!("{}ě", stem)
+    } else {
+        format!("{}e", stem)
+    }
+}
+
+// stem before -i ending: kluk -> kluci, český -> čeští, doktor -> doktoři
+fn palatalize(stem: &str) -> String {
+    let replacements = [("ch", "š"), ("sk", "št"), ("ck", "čt"), ("k", "c"), ("h", "z"), ("g", "z"), ("r", "ř")];
+    for (ending, replacement) in replacements {
+        if let Some(base) = stem.strip_suffix(ending) {
+            return format!("{}{}", base, replacement);
+        }
+    }
+
+    stem.to_string()
+}
+
+// genitive plural without ending, consonant clusters may get inserted e: matka -> matek, okno -> oken
+fn genitive_plural(stem: &str) -> Vec<String> {
+    let mut forms = vec![stem.to_string()];
+    let chars = stem.chars().collect::<Vec<_>>();
+    if chars.len() > 2 {
+        let last = chars[chars.len() - 1];
+        let previous = chars[chars.len() - 2];
+        if CONSONANTS.contains(previous) && "krnlc".contains(last) {
+            let base = chars[..chars.len() - 1].iter().collect::<String>();
+            forms.push(format!("{}e{}", base, last));
+        }
+    }
+    forms
+}
+
+// drop the mobile e of masculine nouns: domek -> domk, chlapec -> chlapc
+fn mobile_e(headword: &str) -> String {
+    if headword.chars().count() > 4 {
+        for ending in ["ek", "ec"] {
+            if let Some(base) = headword.strip_suffix(ending) {
+                if ends_with_any(base, CONSONANTS) {
+                    return format!("{}{}", base, &ending[1..]);
+                }
+            }
+        }
+    }
+
+    headword.to_string()
+}
+
+// imperative of -it verbs: prosit -> pros, vrátit -> vrať, myslit -> mysli
+fn imperative(stem: &str) -> String {
+    let chars = stem.chars().collect::<Vec<_>>();
+    if chars.len() > 1 && CONSONANTS.contains(chars[chars.len() - 1]) && CONSONANTS.contains(chars[chars.len() - 2]) {
+        return format!("{}i", stem);
+    }
+
+    let replacements = [("d", "ď"), ("t", "ť"), ("n", "ň")];
+    for (ending, replacement) in replacements {
+        if let Some(base) = stem.strip_suffix(ending) {
+            return format!("{}{}", base, replacement);
+        }
+    }
+
+    stem.to_string()
+}
+
+// passive participle of -it verbs: prosit -> prošen, platit -> placen, čistit -> čištěn
+fn passive(stem: &str) -> String {
+    let replacements = [("st", "štěn"), ("t", "cen"), ("d", "zen"), ("s", "šen"), ("z", "žen")];
+    for (ending, replacement) in replacements {
+        if let Some(base) = stem.strip_suffix(ending) {
+            return format!("{}{}", base, replacement);
+        }
+    }
+
+    format!("{}en", stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_forms(headword: &str, word_class: WordClass, expected: &[&str]) {
+        let inflections = Czech::new().inflect(headword, &word_class);
+        for form in expected {
+            assert!(inflections.contains(&form.to_string()), "{} should have form {}: {:?}", headword, form, inflections);
+        }
+    }
+
+    fn assert_no_forms(headword: &str, word_class: WordClass, unexpected: &[&str]) {
+        let inflections = Czech::new().inflect(headword, &word_class);
+        for form in unexpected {
+            assert!(!inflections.contains(&form.to_string()), "{} should not have form {}: {:?}", headword, form, inflections);
+        }
+    }
+
+    #[test]
+    fn feminine_hard_noun() {
+        assert_forms("žena", WordClass::Noun, &["ženy", "ženě", "ženu", "ženo", "ženou", "žen", "ženám", "ženách", "ženami"]);
+    }
+
+    #[test]
+    fn neuter_hard_noun() {
+        assert_forms("město", WordClass::Noun, &["města", "městu", "městě", "městem", "městy", "měst", "městům", "městech"]);
+    }
+
+    #[test]
+    fn masculine_animate_hard_noun() {
+        assert_forms("pán", WordClass::Noun, &["pána", "pánovi", "pánem", "páne", "páni", "pánové", "pány", "pánů", "pánům", "pánech"]);
+    }
+
+    #[test]
+    fn masculine_soft_noun() {
+        assert_forms("muž", WordClass::Noun, &["muže", "muži", "mužovi", "mužem", "mužové", "mužů", "mužům", "mužích"]);
+    }
+
+    #[test]
+    fn irregular_noun() {
+        assert_forms("dům", WordClass::Noun, &["domu", "domě", "domem", "domy", "domů", "domech"]);
+    }
+
+    #[test]
+    fn at_verb() {
+        assert_forms("dělat", WordClass::Verb, &["dělám", "děláš", "dělá", "děláme", "dělají", "dělal", "dělala", "dělej", "dělejte", "dělaný"]);
+    }
+
+    #[test]
+    fn it_verb() {
+        assert_forms("prosit", WordClass::Verb, &["prosím", "prosíš", "prosí", "prosíme", "prosil", "prosila", "pros", "proste", "prošen"]);
+    }
+
+    #[test]
+    fn irregular_verb() {
+        assert_forms("psát", WordClass::Verb, &["píšu", "píše", "píšou", "psal", "psala", "psali", "piš", "pište", "psán"]);
+    }
+
+    #[test]
+    fn verb_derived_by_prefix() {
+        assert_forms("přijít", WordClass::Verb, &["přijdu", "přijde", "přišel", "přišla"]);
+        assert_no_forms("přijít", WordClass::Verb, &["připůjdu"]);
+        assert_forms("usmát se", WordClass::Verb, &["usměju se", "usmál se"]);
+    }
+
+    #[test]
+    fn verbs_looking_like_prefixed() {
+        assert_forms("umět", WordClass::Verb, &["umím", "umějí", "uměl"]);
+        assert_forms("smět", WordClass::Verb, &["smím", "smějí", "směl"]);
+        assert_no_forms("dbát", WordClass::Verb, &["dbojím", "dbojí", "dboj"]);
+    }
+
+    #[test]
+    fn multi_word_verb_is_cleaned() {
+        for headword in ["zavřít se", "mít rád", "stát se"] {
+            let inflections = Czech::new().inflect(headword, &WordClass::Verb);
+            let mut cleaned = inflections.clone();
+            cleaned.retain(|form| !form.is_empty() && form != headword);
+            cleaned.sort();
+            cleaned.dedup();
+            assert!(!inflections.is_empty(), "{} should have forms", headword);
+            assert_eq!(inflections, cleaned, "{} forms should be sorted without duplicates", headword);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+
+use crate::dictionary::language::IrregularNounType;
+
+pub fn build() -> IrregularNounType {
+    let dataset = vec![
+        ("člověk", vec!["člověka", "člověku", "člověkem", "člověče", "lidé", "lidi", "lidí", "lidem", "lidech", "lidmi"]),
+        ("dítě", vec!["dítěte", "dítěti", "dítětem", "děti", "dětí", "dětem", "dětech", "dětmi"]),
+        ("dům", vec!["domu", "domě", "domem", "dome", "domy", "domů", "domům", "domech"]),
+        ("pes", vec!["psa", "psovi", "psu", "pse", "psem", "psi", "psů", "psům", "psy", "psech"]),
+        ("oko", vec!["oka", "oku", "okem", "oči", "očí", "očím", "očích", "očima"]),
+        ("ucho", vec!["ucha", "uchu", "uchem", "uši", "uší", "uším", "uších", "ušima"]),
+        ("ruka", vec!["ruky", "ruce", "ruku", "ruko", "rukou", "rukám", "rukách", "rukama"]),
+        ("noha", vec!["nohy", "noze", "nohu", "noho", "nohou", "noh", "nohám", "nohách", "nohama"]),
+        ("bůh", vec!["boha", "bohu", "bože", "bohem", "bohové", "bohů", "bohům", "bohy", "bozích"]),
+        ("stůl", vec!["stolu", "stole", "stolem", "stoly", "stolů", "stolům", "stolech"]),
+        ("kůň", vec!["koně", "koni", "koněm", "koní", "koním", "koních", "koňmi"]),
+        ("den", vec!["dne", "dni", "dnu", "dnem", "dny", "dní", "dnů", "dnům", "dnech"]),
+        ("týden", vec!["týdne", "týdnu", "týdnem", "týdny", "týdnů", "týdnům", "týdnech"]),
+        ("král", vec!["krále", "králi", "králem", "králové", "králů", "králům", "králích"]),
+        ("přítel", vec!["přítele", "příteli", "přítelem", "přátelé", "přátel", "přátelům", "přátelích", "přáteli"]),
+        ("věc", vec!["věci", "věcí", "věcem", "věcech", "věcmi"]),
+        ("noc", vec!["noci", "nocí", "nocím", "nocem", "nocích", "nocech", "nocemi"]),
+        ("loď", vec!["lodi", "lodě", "lodí", "lodím", "lodích", "loděmi", "lodmi"]),
+        ("píseň", vec!["písně", "písni", "písní", "písním", "písních", "písněmi"]),
+        ("paní", vec![]),
+        ("peníze", vec!["peněz", "penězům", "penězích", "penězi"]),
+        ("kuře", vec!["kuřete", "kuřeti", "kuřetem", "kuřata", "kuřat", "kuřatům", "kuřatech", "kuřaty"]),
+        ("kníže", vec!["knížete", "knížeti", "knížetem", "knížata", "knížat", "knížatům", "knížatech", "knížaty"]),
+        ("jméno", vec!["jména", "jménu", "jméně", "jménem", "jmen", "jménům", "jménech", "jmény"]),
+        ("otec", vec!["otce", "otci", "otcem", "otče", "otcové", "otců", "otcům", "otcích"]),
+        ("matka", vec!["matky", "matce", "matku", "matko", "matkou", "matek", "matkám", "matkách", "matkami"]),
+        ("sůl", vec!["soli", "solí", "solím", "solích", "solemi"]),
+        ("myš", vec!["myši", "myší", "myším", "myších", "myšmi"]),
+        ("krev", vec!["krve", "krvi", "krví"]),
+        ("církev", vec!["církve", "církvi", "církví", "církvím", "církvích", "církvemi"]),
+        ("láska", vec!["lásky", "lásce", "lásku", "lásko", "láskou", "lásek", "láskám", "láskách", "láskami"]),
+        ("hrad", vec!["hradu", "hradě", "hradem", "hrady", "hradů", "hradům", "hradech"]),
+        ("les", vec!["lesa", "lesu", "lese", "lesem", "lesy", "lesů", "lesům", "lesích"]),
+        ("vůz", vec!["vozu", "voze", "vozem", "vozy", "vozů", "vozům", "vozech"]),
+        ("nůž", vec!["nože", "noži", "nožem", "nožů", "nožům", "nožích"]),
+        ("vítr", vec!["větru", "větrem", "větry", "větrů", "větrům", "větrech"]),
+    ];
+
+    let mut nouns = IrregularNounType::new();
+
+    for (keyword, variants) in dataset {
+        nouns.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    nouns
+}
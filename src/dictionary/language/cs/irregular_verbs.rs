@@ -0,0 +1,209 @@
+
+use crate::dictionary::language::IrregularVerbType;
+
+pub fn build() -> IrregularVerbType {
+    let dataset = vec![
+        ("být", vec![
+            "jsem", "jsi", "je", "jest", "jsme", "jste", "jsou",
+            "byl", "byla", "bylo", "byli", "byly", "buď", "buďme", "buďte",
+            "budu", "budeš", "bude", "budeme", "budete", "budou", "byv", "jsa", "jsouc",
+        ]),
+        ("mít", vec![
+            "mám", "máš", "má", "máme", "máte", "mají",
+            "měl", "měla", "mělo", "měli", "měly", "měj", "mějme", "mějte", "mající",
+        ]),
+        ("umět", vec![
+            "umím", "umíš", "umí", "umíme", "umíte", "umějí",
+            "uměl", "uměla", "umělo", "uměli", "uměly", "uměj", "umějme", "umějte", "umějící",
+        ]),
+        ("smět", vec![
+            "smím", "smíš", "smí", "smíme", "smíte", "smějí",
+            "směl", "směla", "smělo", "směli", "směly", "smějící",
+        ]),
+        ("jít", vec![
+            "jdu", "jdeš", "jde", "jdeme", "jdete", "jdou",
+            "šel", "šla", "šlo", "šli", "šly", "jdi", "jděme", "jděte", "půjdu", "půjdeš",
+            "půjde", "půjdeme", "půjdete", "půjdou", "jdoucí",
+        ]),
+        ("chtít", vec![
+            "chci", "chceš", "chce", "chceme", "chcete", "chtějí",
+            "chtěl", "chtěla", "chtělo", "chtěli", "chtěly", "chtěj", "chtějte", "chtějící",
+        ]),
+        ("jíst", vec![
+            "jím", "jíš", "jí", "jíme", "jíte", "jedí",
+            "jedl", "jedla", "jedlo", "jedli", "jedly", "jez", "jezme", "jezte", "jeden", "jedený",
+        ]),
+        ("vědět", vec![
+            "vím", "víš", "ví", "víme", "víte", "vědí",
+            "věděl", "věděla", "vědělo", "věděli", "věděly", "věz", "vězte", "vědoucí",
+        ]),
+        ("moci", vec![
+            "můžu", "mohu", "můžeš", "může", "můžeme", "můžete", "můžou", "mohou",
+            "mohl", "mohla", "mohlo", "mohli", "mohly", "moct",
+        ]),
+        ("moct", vec![
+            "můžu", "mohu", "můžeš", "může", "můžeme", "můžete", "můžou", "mohou",
+            "mohl", "mohla", "mohlo", "mohli", "mohly", "moci",
+        ]),
+        ("péci", vec![
+            "peču", "pečeš", "peče", "pečeme", "pečete", "pečou",
+            "pekl", "pekla", "peklo", "pekli", "pekly", "peč", "pečte", "pečen", "péct",
+        ]),
+        ("říci", vec![
+            "řeknu", "řekneš", "řekne", "řekneme", "řeknete", "řeknou",
+            "řekl", "řekla", "řeklo", "řekli", "řekly", "řekni", "řekněte", "řečen", "říct",
+        ]),
+        ("říct", vec![
+            "řeknu", "řekneš", "řekne", "řekneme", "řeknete", "řeknou",
+            "řekl", "řekla", "řeklo", "řekli", "řekly", "řekni", "řekněte", "řečen", "říci",
+        ]),
+        ("brát", vec![
+            "beru", "bereš", "bere", "bereme", "berete", "berou",
+            "bral", "brala", "bralo", "brali", "braly", "ber", "berte", "brán", "berouc",
+        ]),
+        ("psát", vec![
+            "píšu", "píši", "píšeš", "píše", "píšeme", "píšete", "píšou", "píší",
+            "psal", "psala", "psalo", "psali", "psaly", "piš", "pište", "psán", "psaný",
+        ]),
+        ("spát", vec![
+            "spím", "spíš", "spí", "spíme", "spíte",
+            "spal", "spala", "spalo", "spali", "spaly", "spi", "spěte", "spící",
+        ]),
+        ("číst", vec![
+            "čtu", "čteš", "čte", "čteme", "čtete", "čtou",
+            "četl", "četla", "četlo", "četli", "četly", "čti", "čtěte", "čten", "čtený",
+        ]),
+        ("stát", vec![
+            "stojím", "stojíš", "stojí", "stojíme", "stojíte",
+            "stál", "stála", "stálo", "stáli", "stály", "stůj", "stůjte", "stojící",
+        ]),
+        ("dát", vec![
+            "dám", "dáš", "dá", "dáme", "dáte", "dají",
+            "dal", "dala", "dalo", "dali", "daly", "dej", "dejte", "dán", "daný",
+        ]),
+        ("hrát", vec![
+            "hraju", "hraji", "hraješ", "hraje", "hrajeme", "hrajete", "hrajou", "hrají",
+            "hrál", "hrála", "hrálo", "hráli", "hrály", "hraj", "hrajte", "hrán", "hraný",
+        ]),
+        ("pít", vec![
+            "piju", "piji", "piješ", "pije", "pijeme", "pijete", "pijou", "pijí",
+            "pil", "pila", "pilo", "pili", "pily", "pij", "pijte", "pit", "pitý",
+        ]),
+        ("žít", vec![
+            "žiju", "žiji", "žiješ", "žije", "žijeme", "žijete", "žijou", "žijí",
+            "žil", "žila", "žilo", "žili", "žily", "žij", "žijte", "žitý",
+        ]),
+        ("bít", vec![
+            "biju", "biji", "biješ", "bije", "bijeme", "bijete", "bijou", "bijí",
+            "bil", "bila", "bilo", "bili", "bily", "bij", "bijte", "bit",
+        ]),
+        ("krýt", vec![
+            "kryju", "kryji", "kryješ", "kryje", "kryjeme", "kryjete", "kryjou", "kryjí",
+            "kryl", "kryla", "krylo", "kryli", "kryly", "kryj", "kryjte", "kryt",
+        ]),
+        ("mýt", vec![
+            "myju", "myji", "myješ", "myje", "myjeme", "myjete", "myjou", "myjí",
+            "myl", "myla", "mylo", "myli", "myly", "myj", "myjte", "myt",
+        ]),
+        ("jet", vec![
+            "jedu", "jedeš", "jede", "jedeme", "jedete", "jedou",
+            "jel", "jela", "jelo", "jeli", "jely", "jeď", "jeďte", "pojedu", "pojedeš", "pojede",
+        ]),
+        ("vést", vec![
+            "vedu", "vedeš", "vede", "vedeme", "vedete", "vedou",
+            "vedl", "vedla", "vedlo", "vedli", "vedly", "veď", "veďte", "veden",
+        ]),
+        ("nést", vec![
+            "nesu", "neseš", "nese", "neseme", "nesete", "nesou",
+            "nesl", "nesla", "neslo", "nesli", "nesly", "nes", "neste", "nesen",
+        ]),
+        ("vézt", vec![
+            "vezu", "vezeš", "veze", "vezeme", "vezete", "vezou",
+            "vezl", "vezla", "vezlo", "vezli", "vezly", "vez", "vezte", "vezen",
+        ]),
+        ("růst", vec![
+            "rostu", "rosteš", "roste", "rosteme", "rostete", "rostou",
+            "rostl", "rostla", "rostlo", "rostli", "rostly", "rosť", "rosťte",
+        ]),
+        ("vzít", vec![
+            "vezmu", "vezmeš", "vezme", "vezmeme", "vezmete", "vezmou",
+            "vzal", "vzala", "vzalo", "vzali", "vzaly", "vezmi", "vezměte", "vzat",
+        ]),
+        ("začít", vec![
+            "začnu", "začneš", "začne", "začneme", "začnete", "začnou",
+            "začal", "začala", "začalo", "začali", "začaly", "začni", "začněte", "začat",
+        ]),
+        ("zapomenout", vec![
+            "zapomenu", "zapomeneš", "zapomene", "zapomeneme", "zapomenete", "zapomenou",
+            "zapomněl", "zapomněla", "zapomnělo", "zapomněli", "zapomněly", "zapomeň", "zapomeňte",
+        ]),
+        ("umřít", vec![
+            "umřu", "umřeš", "umře", "umřeme", "umřete", "umřou",
+            "umřel", "umřela", "umřelo", "umřeli", "umřely", "umři", "umřete",
+        ]),
+        ("zavřít", vec![
+            "zavřu", "zavřeš", "zavře", "zavřeme", "zavřete", "zavřou",
+            "zavřel", "zavřela", "zavřelo", "zavřeli", "zavřely", "zavři", "zavřete", "zavřen",
+        ]),
+        ("chápat", vec![
+            "chápu", "chápeš", "chápe", "chápeme", "chápete", "chápou",
+            "chápal", "chápala", "chápalo", "chápali", "chápaly", "chápej", "chápejte",
+        ]),
+        ("plakat", vec![
+            "pláču", "pláči", "pláčeš", "pláče", "pláčeme", "pláčete", "pláčou", "pláčí",
+            "plakal", "plakala", "plakalo", "plakali", "plakaly", "plač", "plačte",
+        ]),
+        ("mazat", vec![
+            "mažu", "maži", "mažeš", "maže", "mažeme", "mažete", "mažou", "maží",
+            "mazal", "mazala", "mazalo", "mazali", "mazaly", "maž", "mažte", "mazán",
+        ]),
+        ("kázat", vec![
+            "kážu", "káži", "kážeš", "káže", "kážeme", "kážete", "kážou", "káží",
+            "kázal", "kázala", "kázalo", "kázali", "kázaly", "kaž", "kažte", "kázán",
+        ]),
+        ("lhát", vec![
+            "lžu", "lžeš", "lže", "lžeme", "lžete", "lžou",
+            "lhal", "lhala", "lhalo", "lhali", "lhaly", "lži", "lžete",
+        ]),
+        ("smát", vec![
+            "směju", "směji", "směješ", "směje", "smějeme", "smějete", "smějou", "smějí",
+            "smál", "smála", "smálo", "smáli", "smály", "směj", "smějte",
+        ]),
+        ("bát", vec![
+            "bojím", "bojíš", "bojí", "bojíme", "bojíte",
+            "bál", "bála", "bálo", "báli", "bály", "boj", "bojte",
+        ]),
+        ("poslat", vec![
+            "pošlu", "pošleš", "pošle", "pošleme", "pošlete", "pošlou",
+            "poslal", "poslala", "poslalo", "poslali", "poslaly", "pošli", "pošlete", "poslán",
+        ]),
+        ("zvát", vec![
+            "zvu", "zveš", "zve", "zveme", "zvete", "zvou",
+            "zval", "zvala", "zvalo", "zvali", "zvaly", "zvi", "zvěte", "zván",
+        ]),
+        ("hnát", vec![
+            "ženu", "ženeš", "žene", "ženeme", "ženete", "ženou",
+            "hnal", "hnala", "hnalo", "hnali", "hnaly", "žeň", "žeňte", "hnán",
+        ]),
+        ("přát", vec![
+            "přeju", "přeji", "přeješ", "přeje", "přejeme", "přejete", "přejou", "přejí",
+            "přál", "přála", "přálo", "přáli", "přály", "přej", "přejte",
+        ]),
+        ("znát", vec![
+            "znám", "znáš", "zná", "známe", "znáte", "znají",
+            "znal", "znala", "znalo", "znali", "znaly", "znej", "znejte", "znám", "známý",
+        ]),
+        ("stát se", vec![
+            "stanu se", "staneš se", "stane se", "staneme se", "stanete se", "stanou se",
+            "stal se", "stala se", "stalo se", "stali se", "staly se",
+        ]),
+    ];
+
+    let mut verbs = IrregularVerbType::new();
+
+    for (keyword, variants) in dataset {
+        verbs.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    verbs
+}
@@ -0,0 +1,513 @@
+mod irregular_adjectives;
+mod irregular_nouns;
+mod irregular_verbs;
+mod strong_verbs;
+
+use crate::dictionary::{
+    language::{IrregularAdjectiveType, IrregularNounType, IrregularVerbType, LanguageProcessor},
+    WordClass,
+};
+
+const SEPARABLE_PREFIXES: [&str; 27] = [
+    "ab", "an", "auf", "aus", "bei", "da", "dar", "ein", "entgegen", "fest", "fort", "heim", "her",
+    "heraus", "herein", "hin", "hinaus", "hinein", "los", "mit", "nach", "vor", "vorbei", "weg",
+    "weiter", "zurück", "zusammen",
+];
+
+// consonant clusters a verb may start with, used to tell separable prefixes from a part of the stem
+const ONSETS: [&str; 28] = [
+    "bl", "br", "ch", "dr", "fl", "fr", "gl", "gr", "kl", "kn", "kr", "pf", "pfl", "pl", "pr", "qu",
+    "sch", "schl", "schm", "schn", "schr", "schw", "sp", "spr", "st", "str", "tr", "zw",
+];
+
+const INSEPARABLE_PREFIXES: [&str; 8] = ["be", "emp", "ent", "er", "ge", "miss", "ver", "zer"];
+
+// weak verbs taking inseparable prefixes, other verbs only look prefixed: bellen is not be + llen
+const WEAK_BASE_VERBS: [&str; 40] = [
+    "achten", "antworten", "arbeiten", "bauen", "brauchen", "danken", "decken", "deuten", "dienen", "drücken",
+    "fassen", "folgen", "fühlen", "führen", "hören", "kaufen", "klären", "legen", "leiten", "lernen",
+    "machen", "mischen", "passen", "richten", "sagen", "schenken", "schicken", "setzen", "spielen", "stellen",
+    "stören", "suchen", "teilen", "trauen", "wählen", "warten", "wohnen", "zahlen", "zählen", "ändern",
+];
+
+// positive stems differing from the headword: hoch -> hohe
+const ADJECTIVE_STEMS: [(&str, &str); 1] = [("hoch", "hoh")];
+
+const ADJECTIVE_ENDINGS: [&str; 5] = ["e", "en", "em", "er", "es"];
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+// finite forms and past participles are kept apart because separable prefixes attach differently
+struct VerbForms {
+    finite: Vec<String>,
+    participles: Vec<String>,
+}
+
+pub struct German {
+    irregular_adjectives: IrregularAdjectiveType,
+    irregular_nouns: IrregularNounType,
+    irregular_verbs: IrregularVerbType,
+    strong_verbs: IrregularVerbType,
+}
+
+impl German {
+    pub fn new() -> German {
+        German {
+            irregular_adjectives: irregular_adjectives::build(),
+            irregular_nouns: irregular_nouns::build(),
+            irregular_verbs: irregular_verbs::build(),
+            strong_verbs: strong_verbs::build(),
+        }
+    }
+
+    fn decline_noun(&self, inflections: &mut Vec<String>, headword: &str, gender: Option<Gender>) {
+        // gender is known also if the headword comes with an article: der Hund, die Katze, das Haus
+        let (gender, noun) = match headword.split_once(' ') {
+            Some(("der", noun)) => (Some(Gender::Masculine), noun),
+            Some(("die", noun)) => (Some(Gender::Feminine), noun),
+            Some(("das", noun)) => (Some(Gender::Neuter), noun),
+            Some(_) => return,
+            None => (gender, headword),
+        };
+
+        if let Some(forms) = self.irregular_nouns.get(noun) {
+            inflections.extend(forms.iter().cloned());
+            return;
+        }
+
+        match gender.unwrap_or_else(|| guess_gender(noun)) {
+            Gender::Feminine => {
+                if noun.ends_with("in") {
+                    inflections.push(format!("{}nen", noun));
+                } else if noun.ends_with('e') || noun.ends_with("el") || noun.ends_with("er") {
+                    inflections.push(format!("{}n", noun));
+                } else {
+                    inflections.push(format!("{}en", noun));
+                }
+            },
+            _ => {
+                if ends_with_any(noun, &["chen", "lein", "el", "er", "en"]) {
+                    // plural is the same as singular: Lehrer, Mädchen
+                    inflections.push(format!("{}s", noun));
+                    if !noun.ends_with('n') {
+                        inflections.push(format!("{}n", noun));
+                    }
+                } else if noun.ends_with("tum") {
+                    // Reichtum -> Reichtümer
+                    inflections.push(format!("{}s", noun));
+                    inflections.push(format!("{}er", umlaut(noun)));
+                    inflections.push(format!("{}ern", umlaut(noun)));
+                } else if let Some(stem) = noun.strip_suffix("um") {
+                    inflections.push(format!("{}ums", stem));
+                    inflections.push(format!("{}en", stem));
+                } else if noun.ends_with('e') || ends_with_any(noun, &["ent", "ist", "ant", "loge"]) {
+                    // weak masculine nouns: Junge, Student
+                    inflections.push(format!("{}{}", noun, if noun.ends_with('e') { "n" } else { "en" }));
+                } else if ends_with_any(noun, &["a", "i", "o", "u", "y"]) {
+                    inflections.push(format!("{}s", noun));
+                } else {
+                    // umlauted plurals are listed among irregular nouns: Baum, Dorf
+                    inflections.push(format!("{}s", noun));
+                    inflections.push(format!("{}es", noun));
+                    inflections.push(format!("{}e", noun));
+                    inflections.push(format!("{}en", noun));
+                }
+            },
+        }
+    }
+
+    fn decline_adjective(&self, inflections: &mut Vec<String>, headword: &str) {
+        let stem = if let Some((_, stem)) = ADJECTIVE_STEMS.iter().find(|(adjective, _)| *adjective == headword) {
+            stem.to_string()
+        } else if let Some(base) = headword.strip_suffix("el") {
+            format!("{}l", base)
+        } else if ends_with_any(headword, &["auer", "euer"]) {
+            format!("{}r", &headword[..headword.len() - 2])
+        } else if let Some(base) = headword.strip_suffix('e') {
+            base.to_string()
+        } else {
+            headword.to_string()
+        };
+
+        let comparisons = match self.irregular_adjectives.get(headword) {
+            Some(forms) => forms
+                .chunks(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .collect::<Vec<_>>(),
+            None => {
+                let superlative = if ends_with_any(headword, &["d", "t", "s", "ß", "x", "z", "sch"]) {
+                    format!("{}est", headword.trim_end_matches('e'))
+                } else {
+                    format!("{}st", headword.trim_end_matches('e'))
+                };
+                vec![(format!("{}er", stem), superlative)]
+            },
+        };
+
+        for ending in ADJECTIVE_ENDINGS {
+            inflections.push(format!("{}{}", stem, ending));
+        }
+        for (comparative, superlative) in comparisons {
+            for base in [&comparative, &superlative] {
+                for ending in ADJECTIVE_ENDINGS {
+                    inflections.push(format!("{}{}", base, ending));
+                }
+            }
+            inflections.push(comparative);
+            inflections.push(format!("am {}en", superlative));
+        }
+    }
+
+    // adverbs are not declined and only few compare: gern -> lieber, am liebsten
+    fn compare_adverb(&self, inflections: &mut Vec<String>, headword: &str) {
+        for pair in self.irregular_adjectives.get(headword).into_iter().flat_map(|forms| forms.chunks(2)) {
+            inflections.push(pair[0].clone());
+            inflections.push(format!("am {}en", pair[1]));
+        }
+    }
+
+    fn conjugate_verb(&self, inflections: &mut Vec<String>, headword: &str) {
+        inflections.push(format!("{}d", headword));
+
+        if let Some((prefix, base)) = self.split_separable(headword) {
+            // ankommen -> kommt an, ankommt, angekommen, anzukommen
+            let forms = self.verb_forms(base);
+            for form in forms.finite {
+                inflections.push(format!("{} {}", form, prefix));
+                inflections.push(format!("{}{}", prefix, form));
+            }
+            for participle in forms.participles {
+                inflections.push(format!("{}{}", prefix, participle));
+            }
+            inflections.push(format!("{}zu{}", prefix, base));
+            return;
+        }
+
+        let forms = self.verb_forms(headword);
+        inflections.extend(forms.finite);
+        inflections.extend(forms.participles);
+    }
+
+    fn verb_forms(&self, verb: &str) -> VerbForms {
+        if let Some(forms) = self.irregular_verbs.get(verb) {
+            return VerbForms { finite: forms.clone(), participles: vec![] };
+        }
+
+        if let Some(parts) = self.strong_verbs.get(verb) {
+            return strong_forms(verb, parts);
+        }
+
+        for prefix in INSEPARABLE_PREFIXES {
+            let Some(base) = verb.strip_prefix(prefix) else {
+                continue;
+            };
+
+            // inseparable prefixes replace ge- of past participle: verstehen -> verstanden
+            if let Some(parts) = self.strong_verbs.get(base) {
+                let forms = strong_forms(base, parts);
+                return VerbForms {
+                    finite: forms.finite.iter().map(|form| format!("{}{}", prefix, form)).collect(),
+                    participles: forms.participles
+                        .iter()
+                        .map(|form| format!("{}{}", prefix, form.strip_prefix("ge").unwrap_or(form)))
+                        .collect(),
+                };
+            }
+
+            if WEAK_BASE_VERBS.contains(&base) || self.irregular_verbs.contains_key(base) {
+                return weak_forms(verb, false);
+            }
+        }
+
+        weak_forms(verb, !verb.ends_with("ieren"))
+    }
+
+    // separable prefix and the rest of the verb if it looks like a verb
+    fn split_separable<'a>(&self, verb: &'a str) -> Option<(&'a str, &'a str)> {
+        let mut candidates = SEPARABLE_PREFIXES
+            .iter()
+            .filter_map(|prefix| verb.strip_prefix(prefix).map(|base| (*prefix, base)))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+
+        candidates.into_iter().find(|(_, base)| {
+            if self.strong_verbs.contains_key(*base) || self.irregular_verbs.contains_key(*base) {
+                return true;
+            }
+
+            let onset = base.chars().take_while(|c| !is_vowel(*c)).collect::<String>();
+            let valid_onset = onset.chars().count() == 1 || ONSETS.contains(&onset.as_str());
+            base.chars().count() > 4 && base.ends_with('n') && valid_onset
+        })
+    }
+}
+
+impl LanguageProcessor for German {
+    fn inflect(&self, headword: &str, word_class: &WordClass) -> Vec<String> {
+        self.inflect_with_gender(headword, word_class, None)
+    }
+
+    fn inflect_with_gender(&self, headword: &str, word_class: &WordClass, gender: Option<&str>) -> Vec<String> {
+        let mut inflections = vec![];
+
+        let gender = match gender.and_then(|gender| gender.chars().next()) {
+            Some('m') => Some(Gender::Masculine),
+            Some('f') => Some(Gender::Feminine),
+            Some('n') => Some(Gender::Neuter),
+            _ => None,
+        };
+
+        match word_class {
+            WordClass::Noun => self.decline_noun(&mut inflections, headword, gender),
+            WordClass::Adjective if !headword.contains(' ') => self.decline_adjective(&mut inflections, headword),
+            WordClass::Adverb => self.compare_adverb(&mut inflections, headword),
+            WordClass::Verb if !headword.contains(' ') && headword.ends_with('n') => {
+                self.conjugate_verb(&mut inflections, headword)
+            },
+            _ => (),
+        }
+
+        inflections.retain(|inflection| inflection != headword);
+        inflections.sort();
+        inflections.dedup();
+        inflections
+    }
+}
+
+impl Default for German {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn ends_with_any(word: &str, endings: &[&str]) -> bool {
+    endings.iter().any(|ending| word.ends_with(ending))
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyäöü".contains(c)
+}
+
+fn guess_gender(noun: &str) -> Gender {
+    if ends_with_any(noun, &["e", "ung", "heit", "keit", "schaft", "ion", "tät", "ik", "ei", "in", "ur"]) {
+        Gender::Feminine
+    } else if ends_with_any(noun, &["chen", "lein", "um", "ment", "tum", "nis"]) {
+        Gender::Neuter
+    } else {
+        Gender::Masculine
+    }
+}
+
+// umlaut of the last stem vowel: Baum -> Bäum, Haus -> Häus, Buch -> Büch
+fn umlaut(word: &str) -> String {
+    if let Some(pos) = word.rfind("au") {
+        return format!("{}äu{}", &word[..pos], &word[pos + 2..]);
+    }
+
+    let Some((pos, c)) = word.char_indices().rev().find(|(_, c)| is_vowel(*c)) else {
+        return word.to_string();
+    };
+    let replacement = match c {
+        'a' => 'ä',
+        'o' => 'ö',
+        'u' => 'ü',
+        _ => return word.to_string(),
+    };
+    format!("{}{}{}", &word[..pos], replacement, &word[pos + c.len_utf8()..])
+}
+
+fn verb_stem(verb: &str) -> &str {
+    verb.strip_suffix("en").or_else(|| verb.strip_suffix('n')).unwrap_or(verb)
+}
+
+// stems ending with t, d or consonant followed by m or n need linking e: arbeitest, atmet
+fn needs_e(stem: &str) -> bool {
+    let chars = stem.chars().collect::<Vec<_>>();
+    let Some(&last) = chars.last() else {
+        return false;
+    };
+
+    if last == 't' || last == 'd' {
+        return true;
+    }
+
+    chars.len() > 1 && (last == 'm' || last == 'n') && !is_vowel(chars[chars.len() - 2]) && !"lrhmn".contains(chars[chars.len() - 2])
+}
+
+fn weak_forms(verb: &str, ge: bool) -> VerbForms {
+    let stem = verb_stem(verb);
+    let e = if needs_e(stem) { "e" } else { "" };
+    let du = if ends_with_any(stem, &["s", "ß", "x", "z"]) { "t" } else { "st" };
+
+    let mut finite = vec![
+        format!("{}e", stem),
+        format!("{}{}{}", stem, e, du),
+        format!("{}{}t", stem, e),
+        format!("{}{}te", stem, e),
+        format!("{}{}test", stem, e),
+        format!("{}{}ten", stem, e),
+        format!("{}{}tet", stem, e),
+        stem.to_string(),
+    ];
+
+    // wandern -> wandre, lächeln -> lächle
+    if let Some(base) = stem.strip_suffix("el").or_else(|| stem.strip_suffix("er")) {
+        finite.push(format!("{}{}e", base, &stem[stem.len() - 1..]));
+    }
+
+    let participle = format!("{}{}{}t", if ge { "ge" } else { "" }, stem, e);
+
+    VerbForms { finite, participles: vec![participle] }
+}
+
+fn strong_forms(verb: &str, parts: &[String]) -> VerbForms {
+    let stem = verb_stem(verb);
+    let third = &parts[0];
+    let past = &parts[1];
+    let e = if needs_e(stem) { "e" } else { "" };
+
+    // present stem with vowel change: gibt -> gib, fährt -> fähr, hält -> hält, lädt -> läd
+    let changed = if *third == format!("{}t", stem) || *third == format!("{}et", stem) {
+        None
+    } else if stem.ends_with('t') {
+        Some(third.clone())
+    } else {
+        Some(third.strip_suffix('t').unwrap_or(third).to_string())
+    };
+
+    let mut finite = vec![
+        format!("{}e", stem),
+        third.clone(),
+        format!("{}{}t", stem, e),
+        past.clone(),
+    ];
+
+    match &changed {
+        Some(changed) => {
+            let du = if ends_with_any(changed, &["s", "ß", "x", "z"]) { "t" } else { "st" };
+            finite.push(format!("{}{}", changed, du));
+            // imperative keeps e -> i change but not umlaut: gib, nimm, but fahr
+            if changed.contains(['ä', 'ö', 'ü']) {
+                finite.push(stem.to_string());
+            } else {
+                finite.push(changed.clone());
+            }
+        },
+        None => {
+            let du = if ends_with_any(stem, &["s", "ß", "x", "z"]) { "t" } else { "st" };
+            finite.push(format!("{}{}{}", stem, e, du));
+            finite.push(stem.to_string());
+        },
+    }
+
+    if past.ends_with('e') {
+        // mixed verbs: brachte
+        for ending in ["st", "n", "t"] {
+            finite.push(format!("{}{}", past, ending));
+        }
+    } else {
+        let e = if ends_with_any(past, &["s", "ß", "d", "t", "z"]) { "e" } else { "" };
+        finite.push(format!("{}{}st", past, e));
+        finite.push(format!("{}en", past));
+        finite.push(format!("{}{}t", past, e));
+    }
+
+    VerbForms { finite, participles: vec![parts[2].clone()] }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_forms(headword: &str, word_class: WordClass, expected: &[&str]) {
+        let inflections = German::new().inflect(headword, &word_class);
+        for form in expected {
+            assert!(inflections.contains(&form.to_string()), "{} should have form {}: {:?}", headword, form, inflections);
+        }
+    }
+
+    fn assert_no_forms(headword: &str, word_class: WordClass, unexpected: &[&str]) {
+        let inflections = German::new().inflect(headword, &word_class);
+        for form in unexpected {
+            assert!(!inflections.contains(&form.to_string()), "{} should not have form {}: {:?}", headword, form, inflections);
+        }
+    }
+
+    #[test]
+    fn masculine_noun_without_umlaut() {
+        assert_forms("Hund", WordClass::Noun, &["Hundes", "Hunde", "Hunden"]);
+        assert_no_forms("Hund", WordClass::Noun, &["Hünde", "Hünden"]);
+        assert_no_forms("Tag", WordClass::Noun, &["Täge"]);
+    }
+
+    #[test]
+    fn noun_with_umlaut() {
+        assert_forms("Baum", WordClass::Noun, &["Bäume", "Bäumen"]);
+        assert_forms("Reichtum", WordClass::Noun, &["Reichtums", "Reichtümer"]);
+    }
+
+    #[test]
+    fn noun_by_gender() {
+        assert_forms("Katze", WordClass::Noun, &["Katzen"]);
+        assert_forms("Lehrerin", WordClass::Noun, &["Lehrerinnen"]);
+        assert_forms("der Lehrer", WordClass::Noun, &["Lehrers", "Lehrern"]);
+        assert_forms("Student", WordClass::Noun, &["Studenten"]);
+    }
+
+    #[test]
+    fn adjective() {
+        assert_forms("klein", WordClass::Adjective, &["kleine", "kleinen", "kleiner", "kleinste", "am kleinsten"]);
+        assert_forms("hoch", WordClass::Adjective, &["hohe", "höher", "höchste", "am höchsten"]);
+    }
+
+    #[test]
+    fn adjective_with_alternative_comparison() {
+        assert_forms("wenig", WordClass::Adjective, &["wenige", "wenigen", "weniges", "weniger", "mindere", "am wenigsten", "am mindesten"]);
+    }
+
+    #[test]
+    fn adverb() {
+        assert_forms("gern", WordClass::Adverb, &["lieber", "am liebsten"]);
+        assert_no_forms("gern", WordClass::Adverb, &["gerne", "liebere"]);
+        assert_eq!(German::new().inflect("heute", &WordClass::Adverb), Vec::<String>::new());
+        assert_eq!(German::new().inflect("hier", &WordClass::Adverb), Vec::<String>::new());
+    }
+
+    #[test]
+    fn weak_verb() {
+        assert_forms("machen", WordClass::Verb, &["mache", "machst", "macht", "machte", "gemacht"]);
+        assert_forms("arbeiten", WordClass::Verb, &["arbeitest", "arbeitet", "gearbeitet"]);
+    }
+
+    #[test]
+    fn strong_verb() {
+        assert_forms("geben", WordClass::Verb, &["gibt", "gibst", "gab", "gegeben"]);
+        assert_forms("verstehen", WordClass::Verb, &["versteht", "verstand", "verstanden"]);
+    }
+
+    #[test]
+    fn verb_with_inseparable_prefix() {
+        assert_forms("besuchen", WordClass::Verb, &["besucht", "besuchte"]);
+        assert_no_forms("besuchen", WordClass::Verb, &["gebesucht"]);
+        assert_forms("gehören", WordClass::Verb, &["gehört"]);
+    }
+
+    #[test]
+    fn verbs_looking_like_prefixed() {
+        assert_forms("bellen", WordClass::Verb, &["gebellt"]);
+        assert_forms("ernten", WordClass::Verb, &["geerntet"]);
+        assert_forms("geigen", WordClass::Verb, &["gegeigt"]);
+        assert_forms("bessern", WordClass::Verb, &["gebessert"]);
+    }
+
+    #[test]
+    fn separable_verb() {
+        assert_forms("ankommen", WordClass::Verb, &["kommt an", "ankommt", "angekommen", "anzukommen"]);
+    }
+}
@@ -0,0 +1,38 @@
+
+use crate::dictionary::language::IrregularAdjectiveType;
+
+// comparative and superlative stems of adjectives with irregular or umlauted comparison,
+// alternative comparisons follow in further pairs: weniger, wenigst or minder, mindest
+pub fn build() -> IrregularAdjectiveType {
+    let dataset = vec![
+        ("alt", vec!["älter", "ältest"]),
+        ("arm", vec!["ärmer", "ärmst"]),
+        ("dumm", vec!["dümmer", "dümmst"]),
+        ("gern", vec!["lieber", "liebst"]),
+        ("groß", vec!["größer", "größt"]),
+        ("gut", vec!["besser", "best"]),
+        ("hart", vec!["härter", "härtest"]),
+        ("hoch", vec!["höher", "höchst"]),
+        ("jung", vec!["jünger", "jüngst"]),
+        ("kalt", vec!["kälter", "kältest"]),
+        ("klug", vec!["klüger", "klügst"]),
+        ("krank", vec!["kränker", "kränkst"]),
+        ("kurz", vec!["kürzer", "kürzest"]),
+        ("lang", vec!["länger", "längst"]),
+        ("nah", vec!["näher", "nächst"]),
+        ("scharf", vec!["schärfer", "schärfst"]),
+        ("schwach", vec!["schwächer", "schwächst"]),
+        ("stark", vec!["stärker", "stärkst"]),
+        ("viel", vec!["mehr", "meist"]),
+        ("warm", vec!["wärmer", "wärmst"]),
+        ("wenig", vec!["weniger", "wenigst", "minder", "mindest"]),
+    ];
+
+    let mut adjectives = IrregularAdjectiveType::new();
+
+    for (keyword, variants) in dataset {
+        adjectives.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    adjectives
+}
@@ -0,0 +1,47 @@
+
+use crate::dictionary::language::IrregularNounType;
+
+pub fn build() -> IrregularNounType {
+    let dataset = vec![
+        ("Bruder", vec!["Bruders", "Brüder", "Brüdern"]),
+        ("Herr", vec!["Herrn", "Herren"]),
+        ("Herz", vec!["Herzens", "Herzen"]),
+        ("Mann", vec!["Mannes", "Manns", "Manne", "Männer", "Männern"]),
+        ("Mutter", vec!["Mütter", "Müttern"]),
+        ("Name", vec!["Namens", "Namen"]),
+        ("Tochter", vec!["Töchter", "Töchtern"]),
+        ("Vater", vec!["Vaters", "Väter", "Vätern"]),
+        ("Museum", vec!["Museums", "Museen"]),
+        ("Auge", vec!["Auges", "Augen"]),
+        ("Kind", vec!["Kindes", "Kinds", "Kinde", "Kinder", "Kindern"]),
+        ("Haus", vec!["Hauses", "Hause", "Häuser", "Häusern"]),
+        ("Buch", vec!["Buches", "Buchs", "Buche", "Bücher", "Büchern"]),
+        ("Hand", vec!["Hände", "Händen"]),
+        ("Stadt", vec!["Städte", "Städten"]),
+        ("Nacht", vec!["Nächte", "Nächten"]),
+        ("Frau", vec!["Frauen"]),
+        ("Junge", vec!["Jungen", "Jungs"]),
+        ("Mensch", vec!["Menschen"]),
+        ("Held", vec!["Helden"]),
+        ("Bär", vec!["Bären"]),
+        ("Doktor", vec!["Doktors", "Doktoren"]),
+        ("Baum", vec!["Baums", "Baumes", "Baume", "Bäume", "Bäumen"]),
+        ("Fuß", vec!["Fußes", "Fuße", "Füße", "Füßen"]),
+        ("Kopf", vec!["Kopfs", "Kopfes", "Kopfe", "Köpfe", "Köpfen"]),
+        ("Sohn", vec!["Sohns", "Sohnes", "Sohne", "Söhne", "Söhnen"]),
+        ("Zug", vec!["Zugs", "Zuges", "Zuge", "Züge", "Zügen"]),
+        ("Platz", vec!["Platzes", "Plätze", "Plätzen"]),
+        ("Arzt", vec!["Arztes", "Ärzte", "Ärzten"]),
+        ("Dorf", vec!["Dorfs", "Dorfes", "Dorfe", "Dörfer", "Dörfern"]),
+        ("Land", vec!["Lands", "Landes", "Lande", "Länder", "Ländern"]),
+        ("Wort", vec!["Worts", "Wortes", "Worte", "Wörter", "Wörtern"]),
+    ];
+
+    let mut nouns = IrregularNounType::new();
+
+    for (keyword, variants) in dataset {
+        nouns.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    nouns
+}
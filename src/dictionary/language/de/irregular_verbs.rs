@@ -0,0 +1,57 @@
+
+use crate::dictionary::language::IrregularVerbType;
+
+// verbs with irregular present tense listed with all their forms
+pub fn build() -> IrregularVerbType {
+    let dataset = vec![
+        ("sein", vec![
+            "bin", "bist", "ist", "sind", "seid", "war", "warst", "waren", "wart", "gewesen",
+            "sei", "seiest", "seien", "seiet", "wäre", "wärest", "wärst", "wären", "wäret", "wärt", "seiend",
+        ]),
+        ("haben", vec![
+            "habe", "hast", "hat", "habt", "hatte", "hattest", "hatten", "hattet", "gehabt",
+            "hab", "habest", "habet", "hätte", "hättest", "hätten", "hättet", "habend",
+        ]),
+        ("werden", vec![
+            "werde", "wirst", "wird", "werdet", "wurde", "wurdest", "wurden", "wurdet", "ward",
+            "geworden", "worden", "werdest", "würde", "würdest", "würden", "würdet", "werdend",
+        ]),
+        ("wissen", vec![
+            "weiß", "weißt", "wisst", "wusste", "wusstest", "wussten", "wusstet", "gewusst",
+            "wisse", "wüsste", "wüsstest", "wüssten", "wisset", "wissend",
+        ]),
+        ("tun", vec![
+            "tue", "tu", "tust", "tut", "tat", "tatest", "tatst", "taten", "tatet", "getan", "täte", "täten", "tuend",
+        ]),
+        ("können", vec![
+            "kann", "kannst", "könnt", "konnte", "konntest", "konnten", "konntet", "gekonnt",
+            "könne", "könnte", "könntest", "könnten", "könntet",
+        ]),
+        ("müssen", vec![
+            "muss", "musst", "müsst", "musste", "musstest", "mussten", "musstet", "gemusst",
+            "müsse", "müsste", "müsstest", "müssten", "müsstet",
+        ]),
+        ("dürfen", vec![
+            "darf", "darfst", "dürft", "durfte", "durftest", "durften", "durftet", "gedurft",
+            "dürfe", "dürfte", "dürftest", "dürften", "dürftet",
+        ]),
+        ("sollen", vec![
+            "soll", "sollst", "sollt", "sollte", "solltest", "sollten", "solltet", "gesollt",
+        ]),
+        ("wollen", vec![
+            "will", "willst", "wollt", "wollte", "wolltest", "wollten", "wolltet", "gewollt",
+        ]),
+        ("mögen", vec![
+            "mag", "magst", "mögt", "mochte", "mochtest", "mochten", "mochtet", "gemocht",
+            "möge", "möchte", "möchtest", "möchten", "möchtet",
+        ]),
+    ];
+
+    let mut verbs = IrregularVerbType::new();
+
+    for (keyword, variants) in dataset {
+        verbs.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    verbs
+}
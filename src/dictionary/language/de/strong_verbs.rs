@@ -0,0 +1,138 @@
+
+use crate::dictionary::language::IrregularVerbType;
+
+// principal parts of strong and mixed verbs: 3rd person singular present, simple past, past participle
+pub fn build() -> IrregularVerbType {
+    let dataset = vec![
+        ("backen", vec!["bäckt", "backte", "gebacken"]),
+        ("befehlen", vec!["befiehlt", "befahl", "befohlen"]),
+        ("beginnen", vec!["beginnt", "begann", "begonnen"]),
+        ("beißen", vec!["beißt", "biss", "gebissen"]),
+        ("bergen", vec!["birgt", "barg", "geborgen"]),
+        ("betrügen", vec!["betrügt", "betrog", "betrogen"]),
+        ("biegen", vec!["biegt", "bog", "gebogen"]),
+        ("bieten", vec!["bietet", "bot", "geboten"]),
+        ("binden", vec!["bindet", "band", "gebunden"]),
+        ("bitten", vec!["bittet", "bat", "gebeten"]),
+        ("blasen", vec!["bläst", "blies", "geblasen"]),
+        ("bleiben", vec!["bleibt", "blieb", "geblieben"]),
+        ("braten", vec!["brät", "briet", "gebraten"]),
+        ("brechen", vec!["bricht", "brach", "gebrochen"]),
+        ("brennen", vec!["brennt", "brannte", "gebrannt"]),
+        ("bringen", vec!["bringt", "brachte", "gebracht"]),
+        ("denken", vec!["denkt", "dachte", "gedacht"]),
+        ("dringen", vec!["dringt", "drang", "gedrungen"]),
+        ("empfehlen", vec!["empfiehlt", "empfahl", "empfohlen"]),
+        ("essen", vec!["isst", "aß", "gegessen"]),
+        ("fahren", vec!["fährt", "fuhr", "gefahren"]),
+        ("fallen", vec!["fällt", "fiel", "gefallen"]),
+        ("fangen", vec!["fängt", "fing", "gefangen"]),
+        ("finden", vec!["findet", "fand", "gefunden"]),
+        ("fliegen", vec!["fliegt", "flog", "geflogen"]),
+        ("fliehen", vec!["flieht", "floh", "geflohen"]),
+        ("fließen", vec!["fließt", "floss", "geflossen"]),
+        ("fressen", vec!["frisst", "fraß", "gefressen"]),
+        ("frieren", vec!["friert", "fror", "gefroren"]),
+        ("gebären", vec!["gebiert", "gebar", "geboren"]),
+        ("geben", vec!["gibt", "gab", "gegeben"]),
+        ("gehen", vec!["geht", "ging", "gegangen"]),
+        ("gelingen", vec!["gelingt", "gelang", "gelungen"]),
+        ("gelten", vec!["gilt", "galt", "gegolten"]),
+        ("genießen", vec!["genießt", "genoss", "genossen"]),
+        ("geschehen", vec!["geschieht", "geschah", "geschehen"]),
+        ("gewinnen", vec!["gewinnt", "gewann", "gewonnen"]),
+        ("gießen", vec!["gießt", "goss", "gegossen"]),
+        ("gleichen", vec!["gleicht", "glich", "geglichen"]),
+        ("gleiten", vec!["gleitet", "glitt", "geglitten"]),
+        ("graben", vec!["gräbt", "grub", "gegraben"]),
+        ("greifen", vec!["greift", "griff", "gegriffen"]),
+        ("halten", vec!["hält", "hielt", "gehalten"]),
+        ("hängen", vec!["hängt", "hing", "gehangen"]),
+        ("heben", vec!["hebt", "hob", "gehoben"]),
+        ("heißen", vec!["heißt", "hieß", "geheißen"]),
+        ("helfen", vec!["hilft", "half", "geholfen"]),
+        ("kennen", vec!["kennt", "kannte", "gekannt"]),
+        ("klingen", vec!["klingt", "klang", "geklungen"]),
+        ("kommen", vec!["kommt", "kam", "gekommen"]),
+        ("kriechen", vec!["kriecht", "kroch", "gekrochen"]),
+        ("laden", vec!["lädt", "lud", "geladen"]),
+        ("lassen", vec!["lässt", "ließ", "gelassen"]),
+        ("laufen", vec!["läuft", "lief", "gelaufen"]),
+        ("leiden", vec!["leidet", "litt", "gelitten"]),
+        ("leihen", vec!["leiht", "lieh", "geliehen"]),
+        ("lesen", vec!["liest", "las", "gelesen"]),
+        ("liegen", vec!["liegt", "lag", "gelegen"]),
+        ("lügen", vec!["lügt", "log", "gelogen"]),
+        ("meiden", vec!["meidet", "mied", "gemieden"]),
+        ("messen", vec!["misst", "maß", "gemessen"]),
+        ("nehmen", vec!["nimmt", "nahm", "genommen"]),
+        ("nennen", vec!["nennt", "nannte", "genannt"]),
+        ("pfeifen", vec!["pfeift", "pfiff", "gepfiffen"]),
+        ("raten", vec!["rät", "riet", "geraten"]),
+        ("reiben", vec!["reibt", "rieb", "gerieben"]),
+        ("reißen", vec!["reißt", "riss", "gerissen"]),
+        ("reiten", vec!["reitet", "ritt", "geritten"]),
+        ("rennen", vec!["rennt", "rannte", "gerannt"]),
+        ("riechen", vec!["riecht", "roch", "gerochen"]),
+        ("rufen", vec!["ruft", "rief", "gerufen"]),
+        ("saufen", vec!["säuft", "soff", "gesoffen"]),
+        ("schaffen", vec!["schafft", "schuf", "geschaffen"]),
+        ("scheiden", vec!["scheidet", "schied", "geschieden"]),
+        ("scheinen", vec!["scheint", "schien", "geschienen"]),
+        ("schieben", vec!["schiebt", "schob", "geschoben"]),
+        ("schießen", vec!["schießt", "schoss", "geschossen"]),
+        ("schlafen", vec!["schläft", "schlief", "geschlafen"]),
+        ("schlagen", vec!["schlägt", "schlug", "geschlagen"]),
+        ("schleichen", vec!["schleicht", "schlich", "geschlichen"]),
+        ("schließen", vec!["schließt", "schloss", "geschlossen"]),
+        ("schmelzen", vec!["schmilzt", "schmolz", "geschmolzen"]),
+        ("schneiden", vec!["schneidet", "schnitt", "geschnitten"]),
+        ("schreiben", vec!["schreibt", "schrieb", "geschrieben"]),
+        ("schreien", vec!["schreit", "schrie", "geschrien"]),
+        ("schweigen", vec!["schweigt", "schwieg", "geschwiegen"]),
+        ("schwimmen", vec!["schwimmt", "schwamm", "geschwommen"]),
+        ("schwören", vec!["schwört", "schwor", "geschworen"]),
+        ("sehen", vec!["sieht", "sah", "gesehen"]),
+        ("senden", vec!["sendet", "sandte", "gesandt"]),
+        ("singen", vec!["singt", "sang", "gesungen"]),
+        ("sinken", vec!["sinkt", "sank", "gesunken"]),
+        ("sitzen", vec!["sitzt", "saß", "gesessen"]),
+        ("sprechen", vec!["spricht", "sprach", "gesprochen"]),
+        ("springen", vec!["springt", "sprang", "gesprungen"]),
+        ("stechen", vec!["sticht", "stach", "gestochen"]),
+        ("stehen", vec!["steht", "stand", "gestanden"]),
+        ("stehlen", vec!["stiehlt", "stahl", "gestohlen"]),
+        ("steigen", vec!["steigt", "stieg", "gestiegen"]),
+        ("sterben", vec!["stirbt", "starb", "gestorben"]),
+        ("stinken", vec!["stinkt", "stank", "gestunken"]),
+        ("stoßen", vec!["stößt", "stieß", "gestoßen"]),
+        ("streichen", vec!["streicht", "strich", "gestrichen"]),
+        ("streiten", vec!["streitet", "stritt", "gestritten"]),
+        ("tragen", vec!["trägt", "trug", "getragen"]),
+        ("treffen", vec!["trifft", "traf", "getroffen"]),
+        ("treiben", vec!["treibt", "trieb", "getrieben"]),
+        ("treten", vec!["tritt", "trat", "getreten"]),
+        ("trinken", vec!["trinkt", "trank", "getrunken"]),
+        ("verderben", vec!["verdirbt", "verdarb", "verdorben"]),
+        ("vergessen", vec!["vergisst", "vergaß", "vergessen"]),
+        ("verlieren", vec!["verliert", "verlor", "verloren"]),
+        ("wachsen", vec!["wächst", "wuchs", "gewachsen"]),
+        ("waschen", vec!["wäscht", "wusch", "gewaschen"]),
+        ("weisen", vec!["weist", "wies", "gewiesen"]),
+        ("wenden", vec!["wendet", "wandte", "gewandt"]),
+        ("werben", vec!["wirbt", "warb", "geworben"]),
+        ("werfen", vec!["wirft", "warf", "geworfen"]),
+        ("wiegen", vec!["wiegt", "wog", "gewogen"]),
+        ("winden", vec!["windet", "wand", "gewunden"]),
+        ("ziehen", vec!["zieht", "zog", "gezogen"]),
+        ("zwingen", vec!["zwingt", "zwang", "gezwungen"]),
+    ];
+
+    let mut verbs = IrregularVerbType::new();
+
+    for (keyword, variants) in dataset {
+        verbs.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    verbs
+}
@@ -1,12 +1,42 @@
+mod irregular_adjectives;
+mod irregular_nouns;
 mod irregular_verbs;
 
 use regex::Regex;
 
-use crate::dictionary::{language::{IrregularVerbType, LanguageProcessor}, Dictionary, WordClass};
+use crate::dictionary::{language::{IrregularAdjectiveType, IrregularNounType, IrregularVerbType, LanguageProcessor}, Dictionary, WordClass};
 
+const CONSONANTS: &str = "bcdfghjklmnpqrstvwxyz";
+
+// verbs stressed on the last syllable doubling the final consonant: admit -> admitted, but visit -> visited
+const STRESSED_LAST_SYLLABLE: [&str; 36] = [
+    "abhor", "acquit", "admit", "begin", "commit", "compel", "confer", "control", "defer", "deter",
+    "emit", "equip", "excel", "expel", "forbid", "forget", "incur", "infer", "occur", "omit",
+    "patrol", "permit", "prefer", "propel", "rebel", "recur", "refer", "regret", "repel", "submit",
+    "transfer", "transmit", "upset", "outwit", "beget", "unwrap",
+];
+
+// prefixes keeping the stress of the verb: readmit -> readmitted, but vomit is not v + omit
+const STRESS_PREFIXES: [&str; 6] = ["mis", "out", "over", "re", "un", "under"];
+
+const AUXILIARY_VERBS: [&str; 3] = ["be", "do", "have"];
+
+// endings of inflected forms with endings of possible lemmas: studies -> study, leaves -> leaf
+const LEMMA_ENDINGS: [(&str, &[&str]); 16] = [
+    ("s", &[""]), ("es", &[""]), ("ies", &["y"]), ("ves", &["f", "fe"]), ("men", &["man"]), ("ses", &["sis"]),
+    ("ing", &["", "e"]), ("ying", &["ie"]), ("ed", &["", "e"]), ("ied", &["y"]),
+    ("er", &["", "e"]), ("est", &["", "e"]), ("ier", &["y"]), ("iest", &["y"]), ("ked", &[""]), ("king", &[""]),
+];
+
+const LEMMA_CLASSES: [WordClass; 4] = [WordClass::Noun, WordClass::Verb, WordClass::Adjective, WordClass::Adverb];
+
+// nouns in -man with regular plural
+const REGULAR_MAN: [&str; 6] = ["caiman", "german", "human", "roman", "shaman", "talisman"];
 
 pub struct English {
     word_regex: WordRegex,
+    irregular_adjectives: IrregularAdjectiveType,
+    irregular_nouns: IrregularNounType,
     irregular_verbs: IrregularVerbType,
 }
 
@@ -14,21 +44,84 @@ impl English {
     pub fn new() -> English {
         English {
             word_regex: WordRegex::new(),
+            irregular_adjectives: irregular_adjectives::build(),
+            irregular_nouns: irregular_nouns::build(),
             irregular_verbs: irregular_verbs::build(),
         }
     }
 
     fn pluralize(&self, inflections: &mut Vec<String>, headword: &str) {
-        self.add_s(inflections, headword);
+        let key = Dictionary::word_to_key(headword);
+        let mut plurals = vec![];
+
+        if let Some(forms) = self.irregular_nouns.get(&key) {
+            plurals.extend(forms.iter().cloned());
+        } else if let Some(stem) = headword.strip_suffix("man").filter(|_| !REGULAR_MAN.contains(&key.as_str())) {
+            plurals.push(format!("{}men", stem));
+        } else if let Some(stem) = headword.strip_suffix("sis") {
+            plurals.push(format!("{}ses", stem));
+        } else {
+            plurals.push(self.add_s(headword));
+        }
+
+        // possessives: child's, children's, dogs'
+        inflections.push(format!("{}'s", headword));
+        for plural in plurals {
+            if plural.ends_with('s') {
+                inflections.push(format!("{}'", plural));
+            } else {
+                inflections.push(format!("{}'s", plural));
+            }
+            inflections.push(plural);
+        }
+    }
+
+    // comparative and superlative of short adjectives and adverbs, longer ones use more and most
+    fn compare(&self, inflections: &mut Vec<String>, headword: &str) {
+        if let Some(forms) = self.irregular_adjectives.get(&Dictionary::word_to_key(headword)) {
+            inflections.extend(forms.iter().cloned());
+            return;
+        }
+
+        let syllables = syllables(headword);
+        let short = syllables == 1 || (syllables == 2 && ["y", "le", "er", "ow"].iter().any(|e| headword.ends_with(e)));
+        if headword.contains([' ', '-']) || !short {
+            return;
+        }
+
+        let stem = self.comparison_stem(headword);
+        inflections.push(format!("{}er", stem));
+        inflections.push(format!("{}est", stem));
+    }
+
+    // stem of regular comparative and superlative: large -> larg, happy -> happi, big -> bigg
+    pub fn comparison_stem(&self, headword: &str) -> String {
+        if let Some(stem) = headword.strip_suffix('e') {
+            stem.to_string()
+        } else if let Some(captures) = self.word_regex.re_y_with_consonant.captures(headword) {
+            format!("{}i", captures.get(1).unwrap().as_str())
+        } else if doubles_final_consonant(headword) {
+            format!("{}{}", headword, headword.chars().last().unwrap())
+        } else {
+            headword.to_string()
+        }
     }
 
     fn inflect_verb(&self, inflections: &mut Vec<String>, headword: &str) {
-        self.add_s(inflections, headword);
-        self.add_ing(inflections, headword);
-        self.add_ed(inflections, headword);
+        // third person of auxiliary verbs is listed among irregular forms: is, has, does
+        if !AUXILIARY_VERBS.contains(&Dictionary::word_to_key(headword).as_str()) {
+            inflections.push(self.add_s(headword));
+        }
+        inflections.push(self.add_ing(headword));
+
+        match self.irregular_verbs.get(&Dictionary::word_to_key(headword)) {
+            Some(forms) => inflections.extend(forms.iter().cloned()),
+            None => inflections.push(self.add_ed(headword)),
+        }
     }
 
-    fn add_s(&self, inflections: &mut Vec<String>, headword: &str) {
+    // plural of nouns and third person singular of verbs
+    pub fn add_s(&self, headword: &str) -> String {
         let mut new_word = headword.to_string();
 
         if self.word_regex.re_s.is_match(headword) {
@@ -38,6 +131,10 @@ impl English {
                 "hero"
                 | "potato"
                 | "tomato"
+                | "echo"
+                | "veto"
+                | "torpedo"
+                | "embargo"
                 | "go"
                 | "do" => new_word.push_str("es"),
                 _ => new_word.push('s'),
@@ -53,54 +150,49 @@ impl English {
             new_word.push('s');
         }
 
-        inflections.push(new_word);
+        new_word
     }
 
-    fn add_ing(&self, inflections: &mut Vec<String>, headword: &str) {
+    pub fn add_ing(&self, headword: &str) -> String {
         let mut new_word = headword.to_string();
 
-        if headword.ends_with("ee") {
+        if headword.len() < 3 || ["ee", "ye", "oe"].iter().any(|ending| headword.ends_with(ending)) {
             new_word.push_str("ing");
-        } else if let Some(captures) = self.word_regex.re_e.captures(headword) {
-            new_word = format!("{}ing", captures.get(1).unwrap().as_str());
         } else if let Some(captures) = self.word_regex.re_ie.captures(headword) {
             new_word = format!("{}ying", captures.get(1).unwrap().as_str());
-        } else if let Some(captures) = self.word_regex.re_verb_ends_with_vowel_and_consonant.captures(headword) {
-            let ending = captures.get(1).unwrap().as_str();
-            new_word.push_str(ending);
+        } else if let Some(captures) = self.word_regex.re_e.captures(headword) {
+            new_word = format!("{}ing", captures.get(1).unwrap().as_str());
+        } else if doubles_final_consonant(headword) {
+            new_word.push(headword.chars().last().unwrap());
             new_word.push_str("ing");
+        } else if self.word_regex.re_ic.is_match(headword) {
+            new_word.push_str("king");
         } else {
             new_word.push_str("ing");
         }
 
-        inflections.push(new_word);
+        new_word
     }
 
-    fn add_ed(&self, inflections: &mut Vec<String>, headword: &str) {
+    // regular past and past participle
+    pub fn add_ed(&self, headword: &str) -> String {
         let mut new_word = headword.to_string();
 
-        if self.irregular_verbs.contains_key(&Dictionary::word_to_key(headword)) {
-            let forms = self.irregular_verbs.get(&Dictionary::word_to_key(headword)).unwrap();
-            for form in forms {
-                inflections.push(form.clone());
-            }
-            return;
-        }
-
         if headword.ends_with("e") {
             new_word.push('d');
         } else if let Some(captures) = self.word_regex.re_y_with_consonant.captures(headword) {
             new_word = captures.get(1).unwrap().as_str().to_string();
             new_word.push_str("ied");
-        } else if let Some(captures) = self.word_regex.re_verb_ends_with_vowel_and_consonant.captures(headword) {
-            let ending = captures.get(1).unwrap().as_str();
-            new_word.push_str(ending);
+        } else if doubles_final_consonant(headword) {
+            new_word.push(headword.chars().last().unwrap());
             new_word.push_str("ed");
+        } else if self.word_regex.re_ic.is_match(headword) {
+            new_word.push_str("ked");
         } else {
             new_word.push_str("ed");
         }
 
-        inflections.push(new_word);
+        new_word
     }
 
 }
@@ -112,11 +204,97 @@ impl LanguageProcessor for English {
         match word_class {
             WordClass::Noun => self.pluralize(&mut inflections, headword),
             WordClass::Verb => self.inflect_verb(&mut inflections, headword),
+            WordClass::Adjective => self.compare(&mut inflections, headword),
+            // adverbs in -ly use more and most: quickly
+            WordClass::Adverb if !headword.ends_with("ly") || self.irregular_adjectives.contains_key(headword) => {
+                self.compare(&mut inflections, headword)
+            },
             _ => (),
         }
 
+        inflections.retain(|inflection| inflection != headword);
+        inflections.sort();
+        inflections.dedup();
         inflections
     }
+
+    // candidates from irregular tables and stripped endings confirmed by inflecting them back
+    fn lemmatize(&self, word: &str) -> Vec<(String, WordClass)> {
+        let word = Dictionary::word_to_key(word);
+        let base = word.strip_suffix("'s").or_else(|| word.strip_suffix('\'')).unwrap_or(&word);
+
+        let mut candidates = vec![base.to_string()];
+        for table in [&self.irregular_verbs, &self.irregular_nouns, &self.irregular_adjectives] {
+            candidates.extend(
+                table
+                    .iter()
+                    .filter(|(_, forms)| forms.iter().any(|form| form == base))
+                    .map(|(lemma, _)| lemma.clone())
+            );
+        }
+        for (ending, lemma_endings) in LEMMA_ENDINGS {
+            let Some(stem) = base.strip_suffix(ending) else {
+                continue;
+            };
+            for lemma_ending in lemma_endings {
+                candidates.push(format!("{}{}", stem, lemma_ending));
+            }
+            // doubled final consonant: stopped -> stop
+            let chars = stem.chars().collect::<Vec<_>>();
+            if chars.len() > 2 && chars[chars.len() - 1] == chars[chars.len() - 2] {
+                candidates.push(chars[..chars.len() - 1].iter().collect());
+            }
+        }
+
+        candidates.sort();
+        candidates.dedup();
+
+        let mut lemmas = vec![];
+        for candidate in candidates.into_iter().filter(|candidate| !candidate.is_empty() && *candidate != word) {
+            for word_class in LEMMA_CLASSES {
+                if self.inflect(&candidate, &word_class).contains(&word) {
+                    lemmas.push((candidate.clone(), word_class));
+                }
+            }
+        }
+        lemmas
+    }
+}
+
+// vowel groups without silent final e: big 1, large 1, simple 2, happy 2
+fn syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for (index, c) in word.chars().enumerate() {
+        let vowel = "aeiou".contains(c) || (c == 'y' && index > 0);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") && !word.ends_with("ee") {
+        count -= 1;
+    }
+    count
+}
+
+// consonant, single vowel and consonant at the end of a stressed syllable: stop -> stopped, quit -> quitting
+fn doubles_final_consonant(word: &str) -> bool {
+    let chars = word.chars().collect::<Vec<_>>();
+    let n = chars.len();
+    if n < 3 {
+        return false;
+    }
+
+    let (first, vowel, last) = (chars[n - 3], chars[n - 2], chars[n - 1]);
+    let before_vowel = CONSONANTS.contains(first) || (first == 'u' && n > 3 && chars[n - 4] == 'q');
+    let cvc = before_vowel && "aeiou".contains(vowel) && CONSONANTS.contains(last) && !"wxy".contains(last);
+
+    let stressed = STRESSED_LAST_SYLLABLE.iter().any(|verb| {
+        word.strip_suffix(verb).is_some_and(|prefix| prefix.is_empty() || STRESS_PREFIXES.contains(&prefix))
+    });
+    cvc && (syllables(word) == 1 || stressed)
 }
 
 
@@ -128,7 +306,7 @@ pub struct WordRegex {
     re_o: Regex,
     re_y: Regex,
     re_y_with_consonant: Regex,
-    re_verb_ends_with_vowel_and_consonant: Regex,
+    re_ic: Regex,
 }
 
 impl WordRegex {
@@ -136,11 +314,11 @@ impl WordRegex {
         WordRegex {
             re_e: Regex::new("(.*)e$").unwrap(),
             re_ie: Regex::new("(.*)ie$").unwrap(),
-            re_s: Regex::new("(s|sh|ch|x)$").unwrap(),
+            re_s: Regex::new("(s|sh|ch|x|z)$").unwrap(),
             re_o: Regex::new("o$").unwrap(),
             re_y: Regex::new("y$").unwrap(),
             re_y_with_consonant: Regex::new("(.*[bcdfghjklmnpqrstvwxyz])y$").unwrap(),
-            re_verb_ends_with_vowel_and_consonant: Regex::new(".*[aeiou]([bcdfghjklmnpqrstvwxyz])$").unwrap(),
+            re_ic: Regex::new("[aeiou][bcdfghjklmnpqrstvwxyz]+ic$").unwrap(),
         }
     }
 }
@@ -151,3 +329,41 @@ impl Default for WordRegex {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_forms(headword: &str, word_class: WordClass, expected: &[&str], unexpected: &[&str]) {
+        let inflections = English::new().inflect(headword, &word_class);
+        for form in expected {
+            assert!(inflections.contains(&form.to_string()), "{} should have form {}: {:?}", headword, form, inflections);
+        }
+        for form in unexpected {
+            assert!(!inflections.contains(&form.to_string()), "{} should not have form {}: {:?}", headword, form, inflections);
+        }
+    }
+
+    #[test]
+    fn irregular_plurals() {
+        assert_forms("child", WordClass::Noun, &["children", "child's", "children's"], &["childs"]);
+        assert_forms("analysis", WordClass::Noun, &["analyses"], &["analysiss", "analysises"]);
+        assert_forms("leaf", WordClass::Noun, &["leaves"], &["leafs"]);
+        assert_forms("golf", WordClass::Noun, &["golfs"], &["golves"]);
+    }
+
+    #[test]
+    fn comparison() {
+        assert_forms("big", WordClass::Adjective, &["bigger", "biggest"], &["biger"]);
+        assert_forms("happy", WordClass::Adjective, &["happier", "happiest"], &["happyer"]);
+        assert_forms("good", WordClass::Adjective, &["better", "best"], &["gooder"]);
+    }
+
+    #[test]
+    fn doubling_final_consonant() {
+        assert_forms("visit", WordClass::Verb, &["visited", "visiting", "visits"], &["visitted"]);
+        assert_forms("admit", WordClass::Verb, &["admitted", "admitting", "admits"], &["admited"]);
+        assert_forms("vomit", WordClass::Verb, &["vomited", "vomiting"], &["vomitted", "vomitting"]);
+        assert_forms("readmit", WordClass::Verb, &["readmitted"], &["readmited"]);
+    }
+}
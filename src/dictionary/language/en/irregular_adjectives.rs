@@ -0,0 +1,26 @@
+use crate::dictionary::language::IrregularAdjectiveType;
+
+// comparatives and superlatives of adjectives and adverbs
+pub fn build() -> IrregularAdjectiveType {
+    let dataset = vec![
+        ("bad", vec!["worse", "worst"]),
+        ("badly", vec!["worse", "worst"]),
+        ("early", vec!["earlier", "earliest"]),
+        ("far", vec!["farther", "further", "farthest", "furthest"]),
+        ("good", vec!["better", "best"]),
+        ("ill", vec!["worse", "worst"]),
+        ("little", vec!["less", "least", "littler", "littlest"]),
+        ("many", vec!["more", "most"]),
+        ("much", vec!["more", "most"]),
+        ("old", vec!["older", "oldest", "elder", "eldest"]),
+        ("well", vec!["better", "best"]),
+    ];
+
+    let mut adjectives = IrregularAdjectiveType::new();
+
+    for (keyword, variants) in dataset {
+        adjectives.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    adjectives
+}
@@ -0,0 +1,66 @@
+use crate::dictionary::language::IrregularNounType;
+
+// plurals not following the rules, same form for nouns with unchanged plural
+pub fn build() -> IrregularNounType {
+    let dataset = vec![
+        ("aircraft", vec!["aircraft"]),
+        ("alumnus", vec!["alumni"]),
+        ("appendix", vec!["appendices", "appendixes"]),
+        ("axis", vec!["axes"]),
+        ("bacterium", vec!["bacteria"]),
+        ("beef", vec!["beefs", "beeves"]),
+        ("cactus", vec!["cacti", "cactuses"]),
+        ("calf", vec!["calves"]),
+        ("child", vec!["children"]),
+        ("criterion", vec!["criteria"]),
+        ("curriculum", vec!["curricula", "curriculums"]),
+        ("datum", vec!["data"]),
+        ("deer", vec!["deer"]),
+        ("dwarf", vec!["dwarfs", "dwarves"]),
+        ("elf", vec!["elves"]),
+        ("fish", vec!["fish", "fishes"]),
+        ("foot", vec!["feet"]),
+        ("fungus", vec!["fungi", "funguses"]),
+        ("goose", vec!["geese"]),
+        ("half", vec!["halves"]),
+        ("hoof", vec!["hoofs", "hooves"]),
+        ("index", vec!["indices", "indexes"]),
+        ("knife", vec!["knives"]),
+        ("leaf", vec!["leaves"]),
+        ("life", vec!["lives"]),
+        ("loaf", vec!["loaves"]),
+        ("louse", vec!["lice"]),
+        ("matrix", vec!["matrices", "matrixes"]),
+        ("medium", vec!["media", "mediums"]),
+        ("memorandum", vec!["memoranda", "memorandums"]),
+        ("mouse", vec!["mice"]),
+        ("nucleus", vec!["nuclei"]),
+        ("ox", vec!["oxen"]),
+        ("person", vec!["people", "persons"]),
+        ("phenomenon", vec!["phenomena"]),
+        ("quiz", vec!["quizzes"]),
+        ("radius", vec!["radii", "radiuses"]),
+        ("scarf", vec!["scarves", "scarfs"]),
+        ("self", vec!["selves"]),
+        ("series", vec!["series"]),
+        ("sheep", vec!["sheep"]),
+        ("shelf", vec!["shelves"]),
+        ("species", vec!["species"]),
+        ("stimulus", vec!["stimuli"]),
+        ("syllabus", vec!["syllabi", "syllabuses"]),
+        ("thief", vec!["thieves"]),
+        ("tooth", vec!["teeth"]),
+        ("vertex", vec!["vertices", "vertexes"]),
+        ("wharf", vec!["wharves", "wharfs"]),
+        ("wife", vec!["wives"]),
+        ("wolf", vec!["wolves"]),
+    ];
+
+    let mut nouns = IrregularNounType::new();
+
+    for (keyword, variants) in dataset {
+        nouns.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    nouns
+}
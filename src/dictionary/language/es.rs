@@ -0,0 +1,520 @@
+mod irregular_stems;
+mod irregular_verbs;
+mod stem_changing_verbs;
+
+use std::collections::HashMap;
+
+use crate::dictionary::{language::{IrregularVerbType, LanguageProcessor}, Dictionary, WordClass};
+
+const VOWELS: &str = "aeiouáéíóú";
+const ACCENTS: [(char, char); 5] = [('a', 'á'), ('e', 'é'), ('i', 'í'), ('o', 'ó'), ('u', 'ú')];
+
+// adjectives shortened before a masculine singular noun
+const APOCOPES: [(&str, &str); 8] = [
+    ("alguno", "algún"), ("bueno", "buen"), ("grande", "gran"), ("malo", "mal"),
+    ("ninguno", "ningún"), ("primero", "primer"), ("santo", "san"), ("tercero", "tercer"),
+];
+
+// prefixes irregular and stem-changing verbs take in derived verbs e.g. mantener -> tener,
+// other verbs only look alike: presentar is not pre + sentar, conjugar is not con + jugar
+const DERIVED_VERBS: [(&str, &[&str]); 34] = [
+    ("tener", &["a", "con", "de", "entre", "man", "ob", "re", "sos"]),
+    ("hacer", &["contra", "des", "re"]),
+    ("poner", &["ante", "com", "contra", "de", "dis", "ex", "im", "o", "pos", "pro", "re", "su", "super", "tras", "yux"]),
+    ("venir", &["a", "con", "contra", "de", "inter", "pre", "pro", "sobre"]),
+    ("decir", &["contra", "des", "pre"]),
+    ("traer", &["abs", "a", "con", "contra", "de", "dis", "ex", "re", "sus"]),
+    ("salir", &["sobre"]),
+    ("valer", &["equi", "pre"]),
+    ("caer", &["de", "re"]),
+    ("volver", &["de", "en", "re"]),
+    ("escribir", &["ad", "circun", "de", "des", "ins", "pre", "pro", "re", "sobre", "sub", "sus", "trans"]),
+    ("cubrir", &["des", "en", "re"]),
+    ("abrir", &["entre", "re"]),
+    ("andar", &["des"]),
+    ("producir", &["re"]),
+    ("contar", &["des", "re"]),
+    ("cerrar", &["en"]),
+    ("pensar", &["re"]),
+    ("sentar", &["a"]),
+    ("sentir", &["a", "con", "di", "pre", "re"]),
+    ("mover", &["con", "pro", "re"]),
+    ("probar", &["a", "com", "re"]),
+    ("mostrar", &["de"]),
+    ("pedir", &["des", "im"]),
+    ("seguir", &["con", "per", "pro", "prose"]),
+    ("vestir", &["des", "re"]),
+    ("sonar", &["re"]),
+    ("negar", &["de", "re"]),
+    ("mentir", &["des"]),
+    ("volar", &["sobre"]),
+    ("elegir", &["re"]),
+    ("medir", &["re"]),
+    ("servir", &["de"]),
+    ("dormir", &["a"]),
+];
+
+// comparatives in -or without feminine forms
+const COMPARATIVES: [&str; 4] = ["mayor", "mejor", "menor", "peor"];
+
+#[derive(PartialEq)]
+enum Conjugation {
+    Ar,
+    Er,
+    Ir,
+}
+
+pub struct Spanish {
+    irregular_verbs: IrregularVerbType,
+    irregular_stems: IrregularVerbType,
+    stem_changes: HashMap<String, String>,
+}
+
+impl Spanish {
+    pub fn new() -> Spanish {
+        Spanish {
+            irregular_verbs: irregular_verbs::build(),
+            irregular_stems: irregular_stems::build(),
+            stem_changes: stem_changing_verbs::build(),
+        }
+    }
+
+    fn decline_noun(&self, inflections: &mut Vec<String>, headword: &str, gender: Option<&str>) {
+        inflections.push(plural(headword));
+
+        // nouns of persons used for both genders: niño -> niña, profesor -> profesora
+        if !gender.is_some_and(|gender| gender.contains('m') && gender.contains('f')) {
+            return;
+        }
+        if let Some(stem) = headword.strip_suffix('o') {
+            add_endings(inflections, stem, &["a", "as"]);
+        } else if headword.ends_with("or") {
+            add_endings(inflections, headword, &["a", "as"]);
+        }
+    }
+
+    fn decline_adjective(&self, inflections: &mut Vec<String>, headword: &str) {
+        if let Some((_, short)) = APOCOPES.iter().find(|(word, _)| *word == headword) {
+            inflections.push(short.to_string());
+        }
+
+        if let Some(stem) = headword.strip_suffix('o') {
+            // rojo, roja, rojos, rojas
+            add_endings(inflections, stem, &["a", "os", "as"]);
+            inflections.extend(superlative(stem));
+        } else if COMPARATIVES.contains(&headword) || headword.ends_with("ior") {
+            inflections.push(plural(headword));
+        } else if headword.ends_with("or") {
+            // trabajador, trabajadora
+            add_endings(inflections, headword, &["a", "es", "as"]);
+        } else if ["és", "án", "ón", "ín"].iter().any(|ending| headword.ends_with(ending)) {
+            // inglés, inglesa; alemán, alemana
+            add_endings(inflections, &remove_accents(headword), &["a", "es", "as"]);
+        } else {
+            inflections.push(plural(headword));
+            if let Some(stem) = headword.strip_suffix('e') {
+                inflections.extend(superlative(stem));
+            }
+        }
+    }
+
+    fn conjugate_verb(&self, inflections: &mut Vec<String>, headword: &str) {
+        let key = Dictionary::word_to_key(headword);
+
+        // reflexive verbs: lavarse -> lavo, lavas
+        if let Some(verb) = key.strip_suffix("se").filter(|verb| conjugation(verb).is_some()) {
+            inflections.push(verb.to_string());
+            self.conjugate_verb(inflections, verb);
+            return;
+        }
+
+        if let Some(forms) = self.irregular_verbs.get(&key) {
+            inflections.extend(forms.iter().cloned());
+            return;
+        }
+
+        let Some(conjugation) = conjugation(&key) else {
+            return;
+        };
+
+        let stem = &key[..key.len() - 2];
+        let (prefix, parts) = match find_derived(&self.irregular_stems, &key) {
+            Some((prefix, parts)) => (prefix, parts.as_slice()),
+            None => ("", [].as_slice()),
+        };
+        let part = |index: usize| {
+            parts.get(index).filter(|part| !part.is_empty()).map(|part| format!("{}{}", prefix, part))
+        };
+
+        // stressed stem takes the vowel change, -ir verbs change it also in some unstressed forms
+        let change = find_derived(&self.stem_changes, &key).map(|(_, change)| change.as_str());
+        let strong = change.map_or(stem.to_string(), |change| strengthen(stem, change));
+        let weak = match change {
+            Some(change) if conjugation == Conjugation::Ir => weaken(stem, change),
+            _ => stem.to_string(),
+        };
+
+        let present = match conjugation {
+            Conjugation::Ar => ["o", "as", "a", "amos", "áis", "an"],
+            Conjugation::Er => ["o", "es", "e", "emos", "éis", "en"],
+            Conjugation::Ir => ["o", "es", "e", "imos", "ís", "en"],
+        };
+        for (index, ending) in present.iter().enumerate() {
+            match (index, part(0)) {
+                (0, Some(first_person)) => inflections.push(first_person),
+                (0 | 1 | 2 | 5, _) => inflections.push(join(&key, &strong, ending)),
+                _ => inflections.push(join(&key, stem, ending)),
+            }
+        }
+
+        let subjunctive = match conjugation {
+            Conjugation::Ar => ["e", "es", "e", "emos", "éis", "en"],
+            _ => ["a", "as", "a", "amos", "áis", "an"],
+        };
+        match part(0) {
+            // subjunctive follows irregular first person: tengo -> tenga
+            Some(first_person) => if let Some(base) = first_person.strip_suffix('o') {
+                add_endings(inflections, base, &subjunctive);
+            },
+            None => for (index, ending) in subjunctive.iter().enumerate() {
+                let stem = match index {
+                    3 | 4 => &weak,
+                    _ => &strong,
+                };
+                inflections.push(join(&key, stem, ending));
+            },
+        }
+
+        inflections.push(part(4).unwrap_or_else(|| join(&key, &strong, present[2])));
+        inflections.push(format!("{}d", &key[..key.len() - 1]));
+
+        let third_plural = match part(1) {
+            // strong preterite: tener -> tuve, decir -> dije
+            Some(preterite) => {
+                let plural = if preterite.ends_with('j') { "eron" } else { "ieron" };
+                for (index, ending) in ["e", "iste", "o", "imos", "isteis", plural].iter().enumerate() {
+                    // c is written z before o: hic -> hizo
+                    match preterite.strip_suffix('c') {
+                        Some(base) if index == 2 => inflections.push(format!("{}z{}", base, ending)),
+                        _ => inflections.push(format!("{}{}", preterite, ending)),
+                    }
+                }
+                format!("{}{}", preterite, plural)
+            },
+            None => {
+                let preterite = match conjugation {
+                    Conjugation::Ar => ["é", "aste", "ó", "amos", "asteis", "aron"],
+                    _ => ["í", "iste", "ió", "imos", "isteis", "ieron"],
+                };
+                for (index, ending) in preterite.iter().enumerate() {
+                    let stem = if index == 2 || index == 5 { &weak } else { stem };
+                    inflections.push(join(&key, stem, ending));
+                }
+                inflections.last().cloned().unwrap_or_default()
+            },
+        };
+
+        // imperfect subjunctive is derived from the third person plural of preterite
+        if let Some(base) = third_plural.strip_suffix("ron") {
+            add_endings(inflections, base, &["ra", "ras", "rais", "ran", "se", "ses", "seis", "sen"]);
+            let accented = accent_last_vowel(base);
+            add_endings(inflections, &accented, &["ramos", "semos"]);
+        }
+
+        let imperfect = match conjugation {
+            Conjugation::Ar => ["aba", "abas", "aba", "ábamos", "abais", "aban"],
+            _ => ["ía", "ías", "ía", "íamos", "íais", "ían"],
+        };
+        for ending in imperfect {
+            inflections.push(join(&key, stem, ending));
+        }
+
+        let future = part(2).unwrap_or(key.clone());
+        add_endings(inflections, &future, &["é", "ás", "á", "emos", "éis", "án"]);
+        add_endings(inflections, &future, &["ía", "ías", "ía", "íamos", "íais", "ían"]);
+
+        match conjugation {
+            Conjugation::Ar => inflections.push(format!("{}ando", stem)),
+            _ => inflections.push(join(&key, &weak, "iendo")),
+        }
+
+        let participle = part(3).unwrap_or_else(|| match conjugation {
+            Conjugation::Ar => format!("{}ado", stem),
+            _ => join(&key, stem, "ido"),
+        });
+        if let Some(base) = participle.strip_suffix('o') {
+            add_endings(inflections, base, &["a", "os", "as"]);
+        }
+        inflections.push(participle);
+
+        for index in 5..parts.len() {
+            inflections.extend(part(index));
+        }
+    }
+}
+
+impl LanguageProcessor for Spanish {
+    fn inflect(&self, headword: &str, word_class: &WordClass) -> Vec<String> {
+        self.inflect_with_gender(headword, word_class, None)
+    }
+
+    fn inflect_with_gender(&self, headword: &str, word_class: &WordClass, gender: Option<&str>) -> Vec<String> {
+        let mut inflections = vec![];
+
+        if let Some((word, rest)) = headword.split_once(' ') {
+            // multi word expressions: casa de campo -> casas de campo, tener en cuenta -> tengo en cuenta
+            let mut forms = vec![];
+            match word_class {
+                WordClass::Noun => self.decline_noun(&mut forms, word, gender),
+                WordClass::Verb => self.conjugate_verb(&mut forms, word),
+                _ => (),
+            }
+            inflections.extend(forms.iter().map(|form| format!("{} {}", form, rest)));
+        } else {
+            match word_class {
+                WordClass::Noun => self.decline_noun(&mut inflections, headword, gender),
+                WordClass::Adjective => self.decline_adjective(&mut inflections, headword),
+                WordClass::Verb => self.conjugate_verb(&mut inflections, headword),
+                _ => (),
+            }
+        }
+
+        inflections.retain(|inflection| inflection != headword);
+        inflections.sort();
+        inflections.dedup();
+        inflections
+    }
+}
+
+impl Default for Spanish {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn add_endings(inflections: &mut Vec<String>, stem: &str, endings: &[&str]) {
+    for ending in endings {
+        inflections.push(format!("{}{}", stem, ending));
+    }
+}
+
+fn conjugation(verb: &str) -> Option<Conjugation> {
+    if verb.chars().count() < 3 {
+        None
+    } else if verb.ends_with("ar") {
+        Some(Conjugation::Ar)
+    } else if verb.ends_with("er") {
+        Some(Conjugation::Er)
+    } else if verb.ends_with("ir") {
+        Some(Conjugation::Ir)
+    } else {
+        None
+    }
+}
+
+// the verb itself or a verb derived from it by a prefix it takes: mantener -> tener, devolver -> volver
+fn find_derived<'a, T>(table: &'a HashMap<String, T>, verb: &'a str) -> Option<(&'a str, &'a T)> {
+    if let Some(value) = table.get(verb) {
+        return Some(("", value));
+    }
+
+    DERIVED_VERBS.iter().find_map(|(base, prefixes)| {
+        let prefix = verb.strip_suffix(base).filter(|prefix| prefixes.contains(prefix))?;
+        table.get(*base).map(|value| (prefix, value))
+    })
+}
+
+// stressed stem: pensar -> piens, volver -> vuelv, pedir -> pid, jugar -> jueg
+fn strengthen(stem: &str, change: &str) -> String {
+    match change {
+        "ie" => replace_last(stem, 'e', "ie"),
+        "ue" if stem.contains('o') => replace_last(stem, 'o', "ue"),
+        "ue" => replace_last(stem, 'u', "ue"),
+        _ => replace_last(stem, 'e', "i"),
+    }
+}
+
+// unstressed stem of -ir verbs: sintió, durmió, pidiendo
+fn weaken(stem: &str, change: &str) -> String {
+    match change {
+        "ue" => replace_last(stem, 'o', "u"),
+        _ => replace_last(stem, 'e', "i"),
+    }
+}
+
+fn replace_last(stem: &str, from: char, to: &str) -> String {
+    match stem.rfind(from) {
+        Some(index) => format!("{}{}{}", &stem[..index], to, &stem[index + from.len_utf8()..]),
+        None => stem.to_string(),
+    }
+}
+
+// join stem and ending keeping the pronunciation of the stem: busqué, pague, cojo, sigo, conozco, construyo, leyó
+fn join(infinitive: &str, stem: &str, ending: &str) -> String {
+    let mut chars = ending.chars();
+    let first = chars.next().unwrap_or(' ');
+    let second = chars.next().unwrap_or(' ');
+    let front = "eé".contains(first);
+    let back = "aáoó".contains(first);
+
+    let mut stem = stem.to_string();
+    let mut ending = ending.to_string();
+    let replacements = [
+        ("car", front, "c", "qu"),
+        ("gar", front, "g", "gu"),
+        ("zar", front, "z", "c"),
+        ("ger", back, "g", "j"),
+        ("gir", back, "g", "j"),
+        ("guir", back, "gu", "g"),
+    ];
+    for (verb_ending, applies, from, to) in replacements {
+        if applies && infinitive.ends_with(verb_ending) {
+            if let Some(base) = stem.strip_suffix(from) {
+                stem = format!("{}{}", base, to);
+            }
+        }
+    }
+
+    if back && (infinitive.ends_with("cer") || infinitive.ends_with("cir")) {
+        if let Some(base) = stem.strip_suffix('c') {
+            let after_vowel = base.chars().last().is_some_and(|c| VOWELS.contains(c));
+            stem = format!("{}{}", base, if after_vowel { "zc" } else { "z" });
+        }
+    }
+
+    let vowel_stem = stem.chars().last().is_some_and(|c| "aeiou".contains(c)) && !stem.ends_with("gu") && !stem.ends_with("qu");
+    if vowel_stem && infinitive.ends_with("uir") && !"ií".contains(first) {
+        stem.push('y');
+    } else if vowel_stem && first == 'i' {
+        if VOWELS.contains(second) {
+            ending = format!("y{}", &ending[1..]);
+        } else if !stem.ends_with('u') {
+            ending = format!("í{}", &ending[1..]);
+        }
+    }
+
+    format!("{}{}", stem, ending)
+}
+
+// absolute superlative: rico -> riquísimo, largo -> larguísimo, rápido -> rapidísimo
+fn superlative(stem: &str) -> Vec<String> {
+    let mut stem = remove_accents(stem);
+    for (from, to) in [("c", "qu"), ("g", "gu"), ("z", "c")] {
+        if let Some(base) = stem.strip_suffix(from) {
+            stem = format!("{}{}", base, to);
+            break;
+        }
+    }
+
+    ["ísimo", "ísima", "ísimos", "ísimas"].iter().map(|ending| format!("{}{}", stem, ending)).collect()
+}
+
+// plural of nouns and adjectives: casa -> casas, papel -> papeles, luz -> luces, camión -> camiones, joven -> jóvenes
+fn plural(word: &str) -> String {
+    let Some(last) = word.chars().last() else {
+        return word.to_string();
+    };
+
+    if "aeiouáéó".contains(last) {
+        return format!("{}s", word);
+    }
+    if "íú".contains(last) {
+        return format!("{}es", word);
+    }
+    if let Some(stem) = word.strip_suffix('z') {
+        return format!("{}ces", stem);
+    }
+
+    let stressed_last = word.chars().rev().take(3).any(|c| "áéíóú".contains(c));
+    if stressed_last {
+        return format!("{}es", remove_accents(word));
+    }
+
+    let syllables = vowel_groups(word);
+    if "sx".contains(last) && syllables.len() > 1 {
+        // crisis, lunes, tórax
+        word.to_string()
+    } else if last == 'n' && syllables.len() > 1 && !word.chars().any(|c| "áéíóú".contains(c)) {
+        // stress moves to the antepenultimate syllable and gets written
+        let (start, end) = syllables[syllables.len() - 2];
+        let chars = word.chars().collect::<Vec<_>>();
+        let index = (start..end).find(|&i| "aeo".contains(chars[i])).unwrap_or(end - 1);
+        let accented = chars
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| if i == index { accent(c) } else { c })
+            .collect::<String>();
+        format!("{}es", accented)
+    } else {
+        format!("{}es", word)
+    }
+}
+
+// ranges of vowel characters, approximating syllables
+fn vowel_groups(word: &str) -> Vec<(usize, usize)> {
+    let mut groups: Vec<(usize, usize)> = vec![];
+    for (index, c) in word.chars().enumerate() {
+        if !VOWELS.contains(c) {
+            continue;
+        }
+        match groups.last_mut() {
+            Some((_, end)) if *end == index => *end = index + 1,
+            _ => groups.push((index, index + 1)),
+        }
+    }
+    groups
+}
+
+fn accent(c: char) -> char {
+    ACCENTS.iter().find(|(plain, _)| *plain == c).map_or(c, |(_, accented)| *accented)
+}
+
+fn accent_last_vowel(word: &str) -> String {
+    let mut chars = word.chars().collect::<Vec<_>>();
+    if let Some(index) = chars.iter().rposition(|c| "aeo".contains(*c)) {
+        chars[index] = accent(chars[index]);
+    }
+    chars.into_iter().collect()
+}
+
+fn remove_accents(word: &str) -> String {
+    word.chars()
+        .map(|c| ACCENTS.iter().find(|(_, accented)| *accented == c).map_or(c, |(plain, _)| *plain))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_forms(headword: &str, expected: &[&str], unexpected: &[&str]) {
+        let inflections = Spanish::new().inflect(headword, &WordClass::Verb);
+        for form in expected {
+            assert!(inflections.contains(&form.to_string()), "{} should have form {}: {:?}", headword, form, inflections);
+        }
+        for form in unexpected {
+            assert!(!inflections.contains(&form.to_string()), "{} should not have form {}: {:?}", headword, form, inflections);
+        }
+    }
+
+    #[test]
+    fn irregular_preterite_stem() {
+        assert_forms("hacer", &["hice", "hiciste", "hizo", "hicimos", "hicieron", "hiciera", "haré", "hecho"], &["hico", "hizimos"]);
+        assert_forms("tener", &["tuve", "tuvo", "tuvieron"], &["tení", "tenió"]);
+    }
+
+    #[test]
+    fn derived_irregular_verbs() {
+        assert_forms("mantener", &["mantengo", "mantienes", "mantuve", "mantendré", "mantenga"], &["mantieno"]);
+        assert_forms("deshacer", &["deshago", "deshice", "deshizo", "desharé", "deshecho"], &["deshaco", "deshico"]);
+        assert_forms("devolver", &["devuelvo", "devolvemos", "devuelto"], &["devolvido"]);
+    }
+
+    #[test]
+    fn verbs_looking_like_derived() {
+        assert_forms("presentar", &["presento", "presentas", "presentado"], &["presiento"]);
+        assert_forms("representar", &["represento", "representan"], &["represiento"]);
+        assert_forms("compensar", &["compenso", "compensa"], &["compienso"]);
+        assert_forms("conjugar", &["conjugo", "conjugué", "conjugan"], &["conjuego"]);
+    }
+}
@@ -0,0 +1,43 @@
+
+use crate::dictionary::language::IrregularVerbType;
+
+// irregular principal parts: first person present, preterite stem, future stem, past participle,
+// imperative singular and any other irregular forms, empty string means a regular form
+pub fn build() -> IrregularVerbType {
+    let dataset = vec![
+        ("abrir", vec!["", "", "", "abierto", ""]),
+        ("andar", vec!["", "anduv", "", "", ""]),
+        ("caber", vec!["quepo", "cup", "cabr", "", ""]),
+        ("caer", vec!["caigo", "", "", "caído", ""]),
+        ("conducir", vec!["conduzco", "conduj", "", "", ""]),
+        ("cubrir", vec!["", "", "", "cubierto", ""]),
+        ("decir", vec!["digo", "dij", "dir", "dicho", "di"]),
+        ("escribir", vec!["", "", "", "escrito", ""]),
+        ("hacer", vec!["hago", "hic", "har", "hecho", "haz"]),
+        ("introducir", vec!["introduzco", "introduj", "", "", ""]),
+        ("morir", vec!["", "", "", "muerto", ""]),
+        ("poder", vec!["", "pud", "podr", "", "", "pudiendo"]),
+        ("poner", vec!["pongo", "pus", "pondr", "puesto", "pon"]),
+        ("producir", vec!["produzco", "produj", "", "", ""]),
+        ("querer", vec!["", "quis", "querr", "", ""]),
+        ("reducir", vec!["reduzco", "reduj", "", "", ""]),
+        ("resolver", vec!["", "", "", "resuelto", ""]),
+        ("romper", vec!["", "", "", "roto", ""]),
+        ("saber", vec!["sé", "sup", "sabr", "", "", "sepa", "sepas", "sepamos", "sepáis", "sepan"]),
+        ("salir", vec!["salgo", "", "saldr", "", "sal"]),
+        ("tener", vec!["tengo", "tuv", "tendr", "", "ten"]),
+        ("traducir", vec!["traduzco", "traduj", "", "", ""]),
+        ("traer", vec!["traigo", "traj", "", "traído", ""]),
+        ("valer", vec!["valgo", "", "valdr", "", ""]),
+        ("venir", vec!["vengo", "vin", "vendr", "", "ven"]),
+        ("volver", vec!["", "", "", "vuelto", ""]),
+    ];
+
+    let mut verbs = IrregularVerbType::new();
+
+    for (keyword, variants) in dataset {
+        verbs.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    verbs
+}
@@ -0,0 +1,88 @@
+
+use crate::dictionary::language::IrregularVerbType;
+
+// verbs too irregular to be derived from their stems listed with all their forms
+pub fn build() -> IrregularVerbType {
+    let dataset = vec![
+        ("ser", vec![
+            "soy", "eres", "es", "somos", "sois", "son",
+            "fui", "fuiste", "fue", "fuimos", "fuisteis", "fueron",
+            "era", "eras", "éramos", "erais", "eran",
+            "seré", "serás", "será", "seremos", "seréis", "serán",
+            "sería", "serías", "seríamos", "seríais", "serían",
+            "sea", "seas", "seamos", "seáis", "sean",
+            "fuera", "fueras", "fuéramos", "fuerais", "fueran", "fuese", "fueses", "fuésemos", "fueseis", "fuesen",
+            "sé", "sed", "siendo", "sido",
+        ]),
+        ("ir", vec![
+            "voy", "vas", "va", "vamos", "vais", "van",
+            "fui", "fuiste", "fue", "fuimos", "fuisteis", "fueron",
+            "iba", "ibas", "íbamos", "ibais", "iban",
+            "iré", "irás", "irá", "iremos", "iréis", "irán",
+            "iría", "irías", "iríamos", "iríais", "irían",
+            "vaya", "vayas", "vayamos", "vayáis", "vayan",
+            "fuera", "fueras", "fuéramos", "fuerais", "fueran", "fuese", "fueses", "fuésemos", "fueseis", "fuesen",
+            "ve", "id", "yendo", "ido",
+        ]),
+        ("estar", vec![
+            "estoy", "estás", "está", "estamos", "estáis", "están",
+            "estuve", "estuviste", "estuvo", "estuvimos", "estuvisteis", "estuvieron",
+            "estaba", "estabas", "estábamos", "estabais", "estaban",
+            "estaré", "estarás", "estará", "estaremos", "estaréis", "estarán",
+            "estaría", "estarías", "estaríamos", "estaríais", "estarían",
+            "esté", "estés", "estemos", "estéis", "estén",
+            "estuviera", "estuvieras", "estuviéramos", "estuvierais", "estuvieran",
+            "estuviese", "estuvieses", "estuviésemos", "estuvieseis", "estuviesen",
+            "estad", "estando", "estado",
+        ]),
+        ("haber", vec![
+            "he", "has", "ha", "hay", "hemos", "habéis", "han",
+            "hube", "hubiste", "hubo", "hubimos", "hubisteis", "hubieron",
+            "había", "habías", "habíamos", "habíais", "habían",
+            "habré", "habrás", "habrá", "habremos", "habréis", "habrán",
+            "habría", "habrías", "habríamos", "habríais", "habrían",
+            "haya", "hayas", "hayamos", "hayáis", "hayan",
+            "hubiera", "hubieras", "hubiéramos", "hubierais", "hubieran",
+            "hubiese", "hubieses", "hubiésemos", "hubieseis", "hubiesen",
+            "habiendo", "habido",
+        ]),
+        ("oír", vec![
+            "oigo", "oyes", "oye", "oímos", "oís", "oyen",
+            "oí", "oíste", "oyó", "oísteis", "oyeron",
+            "oía", "oías", "oíamos", "oíais", "oían",
+            "oiré", "oirás", "oirá", "oiremos", "oiréis", "oirán",
+            "oiría", "oirías", "oiríamos", "oiríais", "oirían",
+            "oiga", "oigas", "oigamos", "oigáis", "oigan",
+            "oyera", "oyeras", "oyéramos", "oyerais", "oyeran", "oyese", "oyeses", "oyésemos", "oyeseis", "oyesen",
+            "oíd", "oyendo", "oído",
+        ]),
+        ("dar", vec![
+            "doy", "das", "da", "damos", "dais", "dan",
+            "di", "diste", "dio", "dimos", "disteis", "dieron",
+            "daba", "dabas", "dábamos", "dabais", "daban",
+            "daré", "darás", "dará", "daremos", "daréis", "darán",
+            "daría", "darías", "daríamos", "daríais", "darían",
+            "dé", "des", "demos", "deis", "den",
+            "diera", "dieras", "diéramos", "dierais", "dieran", "diese", "dieses", "diésemos", "dieseis", "diesen",
+            "dad", "dando", "dado",
+        ]),
+        ("ver", vec![
+            "veo", "ves", "ve", "vemos", "veis", "ven",
+            "vi", "viste", "vio", "vimos", "visteis", "vieron",
+            "veía", "veías", "veíamos", "veíais", "veían",
+            "veré", "verás", "verá", "veremos", "veréis", "verán",
+            "vería", "verías", "veríamos", "veríais", "verían",
+            "vea", "veas", "veamos", "veáis", "vean",
+            "viera", "vieras", "viéramos", "vierais", "vieran", "viese", "vieses", "viésemos", "vieseis", "viesen",
+            "ved", "viendo", "visto",
+        ]),
+    ];
+
+    let mut verbs = IrregularVerbType::new();
+
+    for (keyword, variants) in dataset {
+        verbs.insert(keyword.to_string(), variants.iter().map(|v| v.to_string()).collect());
+    }
+
+    verbs
+}
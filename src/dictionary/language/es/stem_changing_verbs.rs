@@ -0,0 +1,22 @@
+
+use std::collections::HashMap;
+
+// stressed stem vowel change of common verbs: e -> ie, o -> ue, e -> i, u -> ue
+pub fn build() -> HashMap<String, String> {
+    let dataset = vec![
+        ("acostar", "ue"), ("almorzar", "ue"), ("cerrar", "ie"), ("comenzar", "ie"), ("competir", "i"),
+        ("conseguir", "i"), ("contar", "ue"), ("costar", "ue"), ("defender", "ie"), ("despertar", "ie"),
+        ("devolver", "ue"), ("divertir", "ie"), ("doler", "ue"), ("dormir", "ue"), ("empezar", "ie"),
+        ("encender", "ie"), ("encontrar", "ue"), ("entender", "ie"), ("elegir", "i"), ("jugar", "ue"),
+        ("llover", "ue"), ("medir", "i"), ("mentir", "ie"), ("morir", "ue"), ("mostrar", "ue"),
+        ("mover", "ue"), ("negar", "ie"), ("nevar", "ie"), ("pedir", "i"), ("pensar", "ie"),
+        ("perder", "ie"), ("poder", "ue"), ("preferir", "ie"), ("probar", "ue"), ("querer", "ie"),
+        ("recomendar", "ie"), ("recordar", "ue"), ("repetir", "i"), ("resolver", "ue"),
+        ("seguir", "i"), ("sentar", "ie"), ("sentir", "ie"), ("servir", "i"), ("soler", "ue"),
+        ("sonar", "ue"), ("soñar", "ue"), ("tener", "ie"), ("vestir", "i"), ("venir", "ie"),
+        ("volar", "ue"), ("volver", "ue"), ("decir", "i"), ("despedir", "i"), ("advertir", "ie"),
+        ("convertir", "ie"), ("sugerir", "ie"), ("atender", "ie"), ("apretar", "ie"), ("calentar", "ie"),
+    ];
+
+    dataset.into_iter().map(|(verb, change)| (verb.to_string(), change.to_string())).collect()
+}
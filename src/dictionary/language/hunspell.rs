@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+use crate::dictionary::{language::LanguageProcessor, WordClass};
+
+// upper half of ISO 8859-2 used by many Central European dictionaries
+const ISO_8859_2: &str = "\u{a0}Ą˘Ł¤ĽŚ§¨ŠŞŤŹ\u{ad}ŽŻ°ą˛ł´ľśˇ¸šşťź˝žżŔÁÂĂÄĹĆÇČÉĘËĚÍÎĎĐŃŇÓÔŐÖ×ŘŮÚŰÜÝŢßŕáâăäĺćçčéęëěíîďđńňóôőö÷řůúűüýţ˙";
+
+enum FlagType {
+    Char,
+    Long,
+    Num,
+}
+
+enum Condition {
+    Any,
+    Char(char),
+    Set(Vec<char>, bool),
+}
+
+struct Affix {
+    strip: String,
+    add: String,
+    continuation: Vec<String>,
+    condition: Vec<Condition>,
+}
+
+struct AffixClass {
+    cross_product: bool,
+    entries: Vec<Affix>,
+}
+
+// inflection generator applying affix rules of a Hunspell dictionary (.aff and .dic files) to its words
+pub struct Hunspell {
+    words: HashMap<String, Vec<Vec<String>>>,
+    prefixes: HashMap<String, AffixClass>,
+    suffixes: HashMap<String, AffixClass>,
+    // forms with these flags are not standalone words
+    skip_flags: Vec<String>,
+    forbidden_flag: Option<String>,
+}
+
+impl Hunspell {
+    // path without extension e.g. /usr/share/hunspell/sk_SK
+    pub fn load(path: &str) -> Result<Hunspell, Box<dyn Error>> {
+        let aff = read_file(&format!("{}.aff", path))?;
+        let encoding = String::from_utf8_lossy(&aff)
+            .lines()
+            .find_map(|line| line.strip_prefix("SET "))
+            .map_or("UTF-8".to_string(), |encoding| encoding.trim().to_uppercase());
+
+        // the word list uses encoding of the affix file
+        let aff = decode(&aff, &encoding)?;
+        let dic = decode(&read_file(&format!("{}.dic", path))?, &encoding)?;
+
+        let mut hunspell = Hunspell {
+            words: HashMap::new(),
+            prefixes: HashMap::new(),
+            suffixes: HashMap::new(),
+            skip_flags: vec![],
+            forbidden_flag: None,
+        };
+
+        let mut flag_type = FlagType::Char;
+        let mut aliases = vec![];
+
+        for line in aff.lines() {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            match fields.as_slice() {
+                ["FLAG", "long", ..] => flag_type = FlagType::Long,
+                ["FLAG", "num", ..] => flag_type = FlagType::Num,
+                ["AF", flags, ..] if !aliases.is_empty() || flags.parse::<usize>().is_err() => {
+                    aliases.push(parse_flags(flags, &flag_type));
+                },
+                // the first AF line holds the number of aliases
+                ["AF", _] => aliases.push(vec![]),
+                ["NEEDAFFIX" | "ONLYINCOMPOUND", flag, ..] => hunspell.skip_flags.push(flag.to_string()),
+                ["FORBIDDENWORD", flag, ..] => hunspell.forbidden_flag = Some(flag.to_string()),
+                [kind @ ("PFX" | "SFX"), flag, cross_product, count] if count.parse::<usize>().is_ok() => {
+                    let classes = if *kind == "PFX" { &mut hunspell.prefixes } else { &mut hunspell.suffixes };
+                    classes.insert(flag.to_string(), AffixClass {
+                        cross_product: *cross_product == "Y",
+                        entries: vec![],
+                    });
+                },
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let classes = if *kind == "PFX" { &mut hunspell.prefixes } else { &mut hunspell.suffixes };
+                    let Some(class) = classes.get_mut(*flag) else {
+                        continue;
+                    };
+                    let (add, continuation) = add.split_once('/').unwrap_or((add, ""));
+                    let continuation = resolve_flags(continuation, &flag_type, &aliases);
+                    let condition = rest.first().unwrap_or(&".");
+                    class.entries.push(Affix {
+                        strip: if *strip == "0" { String::new() } else { strip.to_string() },
+                        add: if add == "0" { String::new() } else { add.to_string() },
+                        continuation,
+                        condition: parse_condition(condition),
+                    });
+                },
+                _ => (),
+            }
+        }
+
+        // the first line of the word list is the number of words
+        for line in dic.lines().skip(1) {
+            let entry = line.split(['\t', ' ']).next().unwrap_or("");
+            let (word, flags) = entry.split_once('/').unwrap_or((entry, ""));
+            if word.is_empty() {
+                continue;
+            }
+            let flags = resolve_flags(flags, &flag_type, &aliases);
+            hunspell.words.entry(word.to_string()).or_default().push(flags);
+        }
+
+        Ok(hunspell)
+    }
+
+    fn generate(&self, word: &str, flags: &[String]) -> Vec<String> {
+        let mut forms = vec![];
+
+        if self.forbidden_flag.as_ref().is_some_and(|flag| flags.contains(flag)) {
+            return forms;
+        }
+
+        // suffixes including suffixes allowed after them, remembering which may combine with prefixes
+        let mut suffixed = vec![];
+        for flag in flags {
+            let Some(class) = self.suffixes.get(flag) else {
+                continue;
+            };
+            for affix in class.entries.iter() {
+                let Some(form) = apply_suffix(word, affix) else {
+                    continue;
+                };
+                for continuation in affix.continuation.iter() {
+                    let Some(inner) = self.suffixes.get(continuation) else {
+                        continue;
+                    };
+                    for inner_affix in inner.entries.iter() {
+                        if let Some(inner_form) = apply_suffix(&form, inner_affix) {
+                            suffixed.push((inner_form, inner_affix.continuation.clone(), class.cross_product));
+                        }
+                    }
+                }
+                suffixed.push((form, affix.continuation.clone(), class.cross_product));
+            }
+        }
+
+        for flag in flags {
+            let Some(class) = self.prefixes.get(flag) else {
+                continue;
+            };
+            for affix in class.entries.iter() {
+                if let Some(form) = apply_prefix(word, affix) {
+                    if self.is_word(&affix.continuation) {
+                        forms.push(form);
+                    }
+                }
+                if !class.cross_product {
+                    continue;
+                }
+                for (suffixed_form, _, _) in suffixed.iter().filter(|(_, _, cross_product)| *cross_product) {
+                    if let Some(form) = apply_prefix(suffixed_form, affix) {
+                        forms.push(form);
+                    }
+                }
+            }
+        }
+
+        forms.extend(
+            suffixed
+                .into_iter()
+                .filter(|(_, continuation, _)| self.is_word(continuation))
+                .map(|(form, _, _)| form)
+        );
+        forms
+    }
+
+    fn is_word(&self, flags: &[String]) -> bool {
+        !flags.iter().any(|flag| self.skip_flags.contains(flag))
+    }
+}
+
+impl LanguageProcessor for Hunspell {
+    // affix flags already distinguish word classes so the class is not used
+    fn inflect(&self, headword: &str, _word_class: &WordClass) -> Vec<String> {
+        let entries = self.words.get(headword).or_else(|| self.words.get(&headword.to_lowercase()));
+        let Some(entries) = entries else {
+            return vec![];
+        };
+
+        let mut inflections = entries
+            .iter()
+            .flat_map(|flags| self.generate(headword, flags))
+            .collect::<Vec<_>>();
+
+        inflections.retain(|inflection| inflection != headword);
+        inflections.sort();
+        inflections.dedup();
+        inflections
+    }
+}
+
+fn read_file(file_name: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(fs::read(file_name).map_err(|err| format!("Cannot read {}: {}", file_name, err))?)
+}
+
+fn decode(bytes: &[u8], encoding: &str) -> Result<String, Box<dyn Error>> {
+    match encoding {
+        "UTF-8" => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        "ISO8859-1" => Ok(bytes.iter().map(|&b| b as char).collect()),
+        "ISO8859-2" => {
+            let upper = ISO_8859_2.chars().collect::<Vec<_>>();
+            Ok(bytes.iter().map(|&b| if b < 0xa0 { b as char } else { upper[b as usize - 0xa0] }).collect())
+        },
+        _ => Err(format!("Unsupported Hunspell encoding: {}", encoding))?,
+    }
+}
+
+fn parse_flags(flags: &str, flag_type: &FlagType) -> Vec<String> {
+    match flag_type {
+        FlagType::Char => flags.chars().map(|c| c.to_string()).collect(),
+        FlagType::Long => flags
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|chunk| chunk.iter().collect())
+            .collect(),
+        FlagType::Num => flags.split(',').filter(|f| !f.is_empty()).map(|f| f.to_string()).collect(),
+    }
+}
+
+// flags may be given by a number of AF alias
+fn resolve_flags(flags: &str, flag_type: &FlagType, aliases: &[Vec<String>]) -> Vec<String> {
+    if !aliases.is_empty() {
+        if let Some(alias) = flags.parse::<usize>().ok().and_then(|index| aliases.get(index)) {
+            return alias.clone();
+        }
+    }
+    parse_flags(flags, flag_type)
+}
+
+// simplified regular expression e.g. [^aeiou]y
+fn parse_condition(condition: &str) -> Vec<Condition> {
+    let mut units = vec![];
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => units.push(Condition::Any),
+            '[' => {
+                let mut set = vec![];
+                let mut negated = false;
+                for c in chars.by_ref() {
+                    match c {
+                        ']' => break,
+                        '^' if set.is_empty() && !negated => negated = true,
+                        c => set.push(c),
+                    }
+                }
+                units.push(Condition::Set(set, negated));
+            },
+            c => units.push(Condition::Char(c)),
+        }
+    }
+    units
+}
+
+fn matches(chars: &[char], condition: &[Condition]) -> bool {
+    chars.len() == condition.len() && chars.iter().zip(condition.iter()).all(|(c, unit)| match unit {
+        Condition::Any => true,
+        Condition::Char(expected) => c == expected,
+        Condition::Set(set, negated) => set.contains(c) != *negated,
+    })
+}
+
+fn apply_suffix(word: &str, affix: &Affix) -> Option<String> {
+    let chars = word.chars().collect::<Vec<_>>();
+    if chars.len() < affix.condition.len() || !matches(&chars[chars.len() - affix.condition.len()..], &affix.condition) {
+        return None;
+    }
+    let stem = word.strip_suffix(affix.strip.as_str())?;
+    if stem.is_empty() {
+        return None;
+    }
+    Some(format!("{}{}", stem, affix.add))
+}
+
+fn apply_prefix(word: &str, affix: &Affix) -> Option<String> {
+    let chars = word.chars().collect::<Vec<_>>();
+    if chars.len() < affix.condition.len() || !matches(&chars[..affix.condition.len()], &affix.condition) {
+        return None;
+    }
+    let stem = word.strip_prefix(affix.strip.as_str())?;
+    if stem.is_empty() {
+        return None;
+    }
+    Some(format!("{}{}", affix.add, stem))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // writes the affix file and word list to a temporary directory
+    fn load(name: &str, aff: &[u8], dic: &[u8]) -> Hunspell {
+        let path = std::env::temp_dir().join(format!("hunspell-test-{}-{}", name, std::process::id()));
+        let path = path.to_str().unwrap();
+        fs::write(format!("{}.aff", path), aff).unwrap();
+        fs::write(format!("{}.dic", path), dic).unwrap();
+        let hunspell = Hunspell::load(path).unwrap();
+        fs::remove_file(format!("{}.aff", path)).unwrap();
+        fs::remove_file(format!("{}.dic", path)).unwrap();
+        hunspell
+    }
+
+    fn forms(hunspell: &Hunspell, word: &str) -> Vec<String> {
+        hunspell.inflect(word, &WordClass::Unknown)
+    }
+
+    #[test]
+    fn suffix_with_condition() {
+        let aff = b"SFX S Y 2\nSFX S y ies [^aeiou]y\nSFX S 0 s [^y]\n";
+        let hunspell = load("condition", aff, b"2\nfly/S\ncat/S\n");
+        assert_eq!(forms(&hunspell, "fly"), ["flies"]);
+        assert_eq!(forms(&hunspell, "cat"), ["cats"]);
+    }
+
+    #[test]
+    fn flag_aliases() {
+        let aff = b"AF 2\nAF SD\nAF D\nSFX S Y 1\nSFX S 0 s .\nSFX D Y 1\nSFX D 0 ed .\n";
+        let hunspell = load("aliases", aff, b"2\nwork/1\nplay/2\n");
+        assert_eq!(forms(&hunspell, "work"), ["worked", "works"]);
+        assert_eq!(forms(&hunspell, "play"), ["played"]);
+    }
+
+    #[test]
+    fn long_flags() {
+        let aff = b"FLAG long\nSFX Aa Y 1\nSFX Aa 0 s .\nSFX Bb Y 1\nSFX Bb 0 ing .\n";
+        let hunspell = load("long", aff, b"1\nwalk/AaBb\n");
+        assert_eq!(forms(&hunspell, "walk"), ["walking", "walks"]);
+    }
+
+    #[test]
+    fn numeric_flags() {
+        let aff = b"FLAG num\nSFX 101 Y 1\nSFX 101 0 s .\nSFX 7 Y 1\nSFX 7 0 ed .\n";
+        let hunspell = load("num", aff, b"1\njump/101,7\n");
+        assert_eq!(forms(&hunspell, "jump"), ["jumped", "jumps"]);
+    }
+
+    #[test]
+    fn cross_product() {
+        let aff = b"PFX U Y 1\nPFX U 0 un .\nSFX D Y 1\nSFX D 0 ed .\nSFX G N 1\nSFX G 0 ing .\n";
+        let hunspell = load("cross", aff, b"1\nlock/UDG\n");
+        assert_eq!(forms(&hunspell, "lock"), ["locked", "locking", "unlock", "unlocked"]);
+    }
+
+    #[test]
+    fn continuation_and_needaffix() {
+        let aff = b"NEEDAFFIX X\nSFX A Y 1\nSFX A 0 ov/BX .\nSFX B Y 1\nSFX B 0 a .\n";
+        let hunspell = load("continuation", aff, b"1\nhrad/A\n");
+        assert_eq!(forms(&hunspell, "hrad"), ["hradova"]);
+    }
+
+    #[test]
+    fn iso_8859_2() {
+        // žena with suffix ě in ISO 8859-2
+        let aff = b"SET ISO8859-2\nSFX A Y 1\nSFX A a \xec a\n";
+        let hunspell = load("iso", aff, b"1\n\xbeena/A\n");
+        assert_eq!(forms(&hunspell, "žena"), ["ženě"]);
+    }
+}
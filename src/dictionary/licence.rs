@@ -0,0 +1,149 @@
+use regex::Regex;
+
+struct Licence {
+    id: &'static str,
+    name: &'static str,
+    url: &'static str,
+    // derived works must be published under the same licence
+    share_alike: bool,
+}
+
+const LICENCES: [Licence; 8] = [
+    Licence {
+        id: "CC-BY-SA-4.0",
+        name: "Creative Commons Attribution-ShareAlike 4.0",
+        url: "https://creativecommons.org/licenses/by-sa/4.0/",
+        share_alike: true,
+    },
+    Licence {
+        id: "CC-BY-SA-3.0",
+        name: "Creative Commons Attribution-ShareAlike 3.0",
+        url: "https://creativecommons.org/licenses/by-sa/3.0/",
+        share_alike: true,
+    },
+    Licence {
+        id: "CC-BY-4.0",
+        name: "Creative Commons Attribution 4.0",
+        url: "https://creativecommons.org/licenses/by/4.0/",
+        share_alike: false,
+    },
+    Licence {
+        id: "GFDL-1.1",
+        name: "GNU Free Documentation License 1.1",
+        url: "https://www.gnu.org/licenses/old-licenses/fdl-1.1.html",
+        share_alike: true,
+    },
+    Licence {
+        id: "GFDL-1.3",
+        name: "GNU Free Documentation License 1.3",
+        url: "https://www.gnu.org/licenses/fdl-1.3.html",
+        share_alike: true,
+    },
+    Licence {
+        id: "MIT",
+        name: "MIT License",
+        url: "https://opensource.org/licenses/MIT",
+        share_alike: false,
+    },
+    Licence {
+        id: "CC0-1.0",
+        name: "Creative Commons Zero 1.0",
+        url: "https://creativecommons.org/publicdomain/zero/1.0/",
+        share_alike: false,
+    },
+    Licence {
+        id: "PD",
+        name: "Public domain",
+        url: "",
+        share_alike: false,
+    },
+];
+
+// share-alike licences whose content may be published under the other one
+const COMPATIBLE: [(&str, &str); 1] = [("CC-BY-SA-3.0", "CC-BY-SA-4.0")];
+
+// input data of a dictionary with its licence
+#[derive(Debug, Clone)]
+pub struct Source {
+    pub id: String,
+    licence: String,
+    attribution: String,
+    date: Option<String>,
+}
+
+impl Source {
+    pub fn new(id: &str, file_name: &str, licences: &[(String, String)]) -> Source {
+        let (licence, attribution) = match id {
+            "wiki" => ("CC-BY-SA-4.0", "Wiktionary contributors, https://www.wiktionary.org".to_string()),
+            "gnu-fdl" => ("GFDL-1.1", "GNU/FDL English-Czech dictionary, https://www.svobodneslovniky.cz".to_string()),
+            _ => ("", format!("{}, {}", id, file_name.rsplit('/').next().unwrap_or(file_name))),
+        };
+        let licence = licences
+            .iter()
+            .find(|(source, _)| source == id)
+            .map_or(licence, |(_, licence)| licence.as_str());
+
+        Source {
+            id: id.to_string(),
+            licence: licence.to_string(),
+            attribution,
+            date: dump_date(file_name),
+        }
+    }
+
+    pub fn licence_name(&self) -> &str {
+        match find(&self.licence) {
+            Some(licence) => licence.name,
+            None if self.licence.is_empty() => "unknown licence",
+            None => &self.licence,
+        }
+    }
+
+    pub fn licence_url(&self) -> &str {
+        find(&self.licence).map_or("", |licence| licence.url)
+    }
+
+    // who made the data and when e.g. Wiktionary contributors, https://www.wiktionary.org (2025-01-01)
+    pub fn attribution(&self) -> String {
+        match &self.date {
+            Some(date) => format!("{} ({})", self.attribution, date),
+            None => self.attribution.clone(),
+        }
+    }
+}
+
+fn find(id: &str) -> Option<&'static Licence> {
+    LICENCES.iter().find(|licence| licence.id.eq_ignore_ascii_case(id))
+}
+
+// date of wiktionary dump is part of its file name e.g. enwiktionary-20250101-pages-articles.xml.bz2
+fn dump_date(file_name: &str) -> Option<String> {
+    let re = Regex::new(r"(?:^|[^0-9])((?:19|20)[0-9]{2})([01][0-9])([0-3][0-9])(?:[^0-9]|$)").unwrap();
+    let name = file_name.rsplit('/').next().unwrap_or(file_name);
+    re.captures(name).map(|captures| format!("{}-{}-{}", &captures[1], &captures[2], &captures[3]))
+}
+
+// reasons why the sources cannot be published together in one dictionary
+pub fn conflicts(sources: &[&Source]) -> Vec<String> {
+    let mut conflicts = vec![];
+    for source in sources.iter().filter(|source| find(&source.licence).is_none()) {
+        conflicts.push(format!("Licence of source {} is unknown: {}", source.id, source.licence_name()));
+    }
+
+    let share_alike = sources
+        .iter()
+        .filter(|source| find(&source.licence).is_some_and(|licence| licence.share_alike))
+        .collect::<Vec<_>>();
+    for (i, a) in share_alike.iter().enumerate() {
+        for b in share_alike[i + 1..].iter() {
+            let (a_id, b_id) = (find(&a.licence).unwrap().id, find(&b.licence).unwrap().id);
+            if a_id != b_id && !COMPATIBLE.contains(&(a_id, b_id)) && !COMPATIBLE.contains(&(b_id, a_id)) {
+                conflicts.push(format!(
+                    "Licences of sources {} ({}) and {} ({}) cannot be combined",
+                    a.id, a.licence_name(), b.id, b.licence_name(),
+                ));
+            }
+        }
+    }
+    conflicts
+}
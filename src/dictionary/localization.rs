@@ -0,0 +1,94 @@
+mod cs;
+mod de;
+mod es;
+mod sk;
+mod zh;
+
+use crate::dictionary::WordClass;
+
+// headings of dictionary information
+pub enum Heading {
+    Author,
+    SourceLanguage,
+    TargetLanguage,
+    Records,
+    Licence,
+    Date,
+    Sources,
+    HowToUse,
+    Abbreviations,
+    WordClasses,
+    Pronunciations,
+}
+
+// longer texts of front matter pages
+pub enum Text {
+    UsageLookup,
+    UsageEntry,
+    UnknownWordClass,
+    WikiPronunciation,
+}
+
+// labels shown to readers in given language, English when the language has no translation
+pub fn word_class(language: &str, word_class: &WordClass) -> &'static str {
+    match language {
+        "cs" => cs::word_class(word_class),
+        "de" => de::word_class(word_class),
+        "es" => es::word_class(word_class),
+        "sk" => sk::word_class(word_class),
+        "zh" => zh::word_class(word_class),
+        _ => word_class.as_str(),
+    }
+}
+
+pub fn heading(language: &str, heading: &Heading) -> &'static str {
+    match language {
+        "cs" => cs::heading(heading),
+        "de" => de::heading(heading),
+        "es" => es::heading(heading),
+        "sk" => sk::heading(heading),
+        "zh" => zh::heading(heading),
+        _ => match heading {
+            Heading::Author => "Author",
+            Heading::SourceLanguage => "Source language",
+            Heading::TargetLanguage => "Target language",
+            Heading::Records => "Records",
+            Heading::Licence => "Licence",
+            Heading::Date => "Date",
+            Heading::Sources => "Sources",
+            Heading::HowToUse => "How to use the dictionary",
+            Heading::Abbreviations => "Abbreviations and labels",
+            Heading::WordClasses => "Word classes",
+            Heading::Pronunciations => "Pronunciation",
+        },
+    }
+}
+
+pub fn text(language: &str, text: &Text) -> &'static str {
+    match language {
+        "cs" => cs::text(text),
+        "de" => de::text(text),
+        "es" => es::text(text),
+        "sk" => sk::text(text),
+        "zh" => zh::text(text),
+        _ => match text {
+            Text::UsageLookup => "Select a word while reading a book to see its entry. Inflected forms lead to the entry of their basic form.",
+            Text::UsageEntry => "An entry starts with the headword and its pronunciation followed by word classes. Each word class lists translations first and then numbered meanings.",
+            Text::UnknownWordClass => "word class not stated in the source",
+            Text::WikiPronunciation => "pronunciation from Wiktionary",
+        },
+    }
+}
+
+// well known pronunciation names are translated, other names given on command line are kept
+pub fn pronunciation_name<'a>(language: &str, name: &'a str) -> &'a str {
+    let translated = match language {
+        "cs" => cs::pronunciation_name(name),
+        "de" => de::pronunciation_name(name),
+        "es" => es::pronunciation_name(name),
+        "sk" => sk::pronunciation_name(name),
+        "zh" => zh::pronunciation_name(name),
+        _ => None,
+    };
+    translated.unwrap_or(name)
+}
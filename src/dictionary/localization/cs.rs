@@ -0,0 +1,58 @@
+use crate::dictionary::{localization::{Heading, Text}, WordClass};
+
+pub fn word_class(word_class: &WordClass) -> &'static str {
+    match word_class {
+        WordClass::Noun => "podstatné jméno",
+        WordClass::Verb => "sloveso",
+        WordClass::Adjective => "přídavné jméno",
+        WordClass::Adverb => "příslovce",
+        WordClass::Determiner => "determinativ",
+        WordClass::Preposition => "předložka",
+        WordClass::Pronoun => "zájmeno",
+        WordClass::Conjunction => "spojka",
+        WordClass::Interjection => "citoslovce",
+        WordClass::Numeral => "číslovka",
+        WordClass::Particle => "částice",
+        WordClass::ProperNoun => "vlastní jméno",
+        WordClass::Article => "člen",
+        WordClass::Phrase => "fráze",
+        WordClass::Idiom => "idiom",
+        WordClass::Prefix => "předpona",
+        WordClass::Suffix => "přípona",
+        WordClass::Abbreviation => "zkratka",
+        WordClass::Unknown => "ostatní",
+    }
+}
+
+pub fn heading(heading: &Heading) -> &'static str {
+    match heading {
+        Heading::Author => "Autor",
+        Heading::SourceLanguage => "Zdrojový jazyk",
+        Heading::TargetLanguage => "Cílový jazyk",
+        Heading::Records => "Počet hesel",
+        Heading::Licence => "Licence",
+        Heading::Date => "Datum",
+        Heading::Sources => "Zdroje",
+        Heading::HowToUse => "Jak používat slovník",
+        Heading::Abbreviations => "Zkratky a značky",
+        Heading::WordClasses => "Slovní druhy",
+        Heading::Pronunciations => "Výslovnost",
+    }
+}
+
+pub fn text(text: &Text) -> &'static str {
+    match text {
+        Text::UsageLookup => "Při čtení knihy označte slovo a zobrazí se jeho heslo. Ohýbané tvary vedou k heslu základního tvaru.",
+        Text::UsageEntry => "Heslo začíná hledaným slovem a výslovností, následují slovní druhy. U každého slovního druhu jsou nejdříve uvedeny překlady a pak číslované významy.",
+        Text::UnknownWordClass => "slovní druh není ve zdroji uveden",
+        Text::WikiPronunciation => "výslovnost z Wikislovníku",
+    }
+}
+
+pub fn pronunciation_name(name: &str) -> Option<&'static str> {
+    match name {
+        "US" => Some("americká"),
+        "UK" | "GB" => Some("britská"),
+        _ => None,
+    }
+}
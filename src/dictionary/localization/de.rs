@@ -0,0 +1,58 @@
+use crate::dictionary::{localization::{Heading, Text}, WordClass};
+
+pub fn word_class(word_class: &WordClass) -> &'static str {
+    match word_class {
+        WordClass::Noun => "Substantiv",
+        WordClass::Verb => "Verb",
+        WordClass::Adjective => "Adjektiv",
+        WordClass::Adverb => "Adverb",
+        WordClass::Determiner => "Determinativ",
+        WordClass::Preposition => "Präposition",
+        WordClass::Pronoun => "Pronomen",
+        WordClass::Conjunction => "Konjunktion",
+        WordClass::Interjection => "Interjektion",
+        WordClass::Numeral => "Numerale",
+        WordClass::Particle => "Partikel",
+        WordClass::ProperNoun => "Eigenname",
+        WordClass::Article => "Artikel",
+        WordClass::Phrase => "Phrase",
+        WordClass::Idiom => "Redewendung",
+        WordClass::Prefix => "Präfix",
+        WordClass::Suffix => "Suffix",
+        WordClass::Abbreviation => "Abkürzung",
+        WordClass::Unknown => "Sonstiges",
+    }
+}
+
+pub fn heading(heading: &Heading) -> &'static str {
+    match heading {
+        Heading::Author => "Autor",
+        Heading::SourceLanguage => "Ausgangssprache",
+        Heading::TargetLanguage => "Zielsprache",
+        Heading::Records => "Einträge",
+        Heading::Licence => "Lizenz",
+        Heading::Date => "Datum",
+        Heading::Sources => "Quellen",
+        Heading::HowToUse => "Benutzung des Wörterbuchs",
+        Heading::Abbreviations => "Abkürzungen und Bezeichnungen",
+        Heading::WordClasses => "Wortarten",
+        Heading::Pronunciations => "Aussprache",
+    }
+}
+
+pub fn text(text: &Text) -> &'static str {
+    match text {
+        Text::UsageLookup => "Markieren Sie beim Lesen eines Buches ein Wort, um seinen Eintrag anzuzeigen. Flektierte Formen führen zum Eintrag der Grundform.",
+        Text::UsageEntry => "Ein Eintrag beginnt mit dem Stichwort und der Aussprache, danach folgen die Wortarten. Bei jeder Wortart stehen zuerst die Übersetzungen und dann die nummerierten Bedeutungen.",
+        Text::UnknownWordClass => "Wortart in der Quelle nicht angegeben",
+        Text::WikiPronunciation => "Aussprache aus dem Wiktionary",
+    }
+}
+
+pub fn pronunciation_name(name: &str) -> Option<&'static str> {
+    match name {
+        "US" => Some("amerikanisch"),
+        "UK" | "GB" => Some("britisch"),
+        _ => None,
+    }
+}
@@ -0,0 +1,58 @@
+use crate::dictionary::{localization::{Heading, Text}, WordClass};
+
+pub fn word_class(word_class: &WordClass) -> &'static str {
+    match word_class {
+        WordClass::Noun => "sustantivo",
+        WordClass::Verb => "verbo",
+        WordClass::Adjective => "adjetivo",
+        WordClass::Adverb => "adverbio",
+        WordClass::Determiner => "determinante",
+        WordClass::Preposition => "preposición",
+        WordClass::Pronoun => "pronombre",
+        WordClass::Conjunction => "conjunción",
+        WordClass::Interjection => "interjección",
+        WordClass::Numeral => "numeral",
+        WordClass::Particle => "partícula",
+        WordClass::ProperNoun => "nombre propio",
+        WordClass::Article => "artículo",
+        WordClass::Phrase => "frase",
+        WordClass::Idiom => "modismo",
+        WordClass::Prefix => "prefijo",
+        WordClass::Suffix => "sufijo",
+        WordClass::Abbreviation => "abreviatura",
+        WordClass::Unknown => "otros",
+    }
+}
+
+pub fn heading(heading: &Heading) -> &'static str {
+    match heading {
+        Heading::Author => "Autor",
+        Heading::SourceLanguage => "Idioma de origen",
+        Heading::TargetLanguage => "Idioma de destino",
+        Heading::Records => "Entradas",
+        Heading::Licence => "Licencia",
+        Heading::Date => "Fecha",
+        Heading::Sources => "Fuentes",
+        Heading::HowToUse => "Cómo usar el diccionario",
+        Heading::Abbreviations => "Abreviaturas y etiquetas",
+        Heading::WordClasses => "Categorías gramaticales",
+        Heading::Pronunciations => "Pronunciación",
+    }
+}
+
+pub fn text(text: &Text) -> &'static str {
+    match text {
+        Text::UsageLookup => "Al leer un libro, seleccione una palabra para ver su entrada. Las formas flexionadas llevan a la entrada de la forma básica.",
+        Text::UsageEntry => "Cada entrada empieza con el lema y la pronunciación, seguidos de las categorías gramaticales. En cada categoría aparecen primero las traducciones y después los significados numerados.",
+        Text::UnknownWordClass => "categoría gramatical no indicada en la fuente",
+        Text::WikiPronunciation => "pronunciación de Wikcionario",
+    }
+}
+
+pub fn pronunciation_name(name: &str) -> Option<&'static str> {
+    match name {
+        "US" => Some("estadounidense"),
+        "UK" | "GB" => Some("británica"),
+        _ => None,
+    }
+}
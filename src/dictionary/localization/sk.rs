@@ -0,0 +1,58 @@
+use crate::dictionary::{localization::{Heading, Text}, WordClass};
+
+pub fn word_class(word_class: &WordClass) -> &'static str {
+    match word_class {
+        WordClass::Noun => "podstatné meno",
+        WordClass::Verb => "sloveso",
+        WordClass::Adjective => "prídavné meno",
+        WordClass::Adverb => "príslovka",
+        WordClass::Determiner => "determinatív",
+        WordClass::Preposition => "predložka",
+        WordClass::Pronoun => "zámeno",
+        WordClass::Conjunction => "spojka",
+        WordClass::Interjection => "citoslovce",
+        WordClass::Numeral => "číslovka",
+        WordClass::Particle => "častica",
+        WordClass::ProperNoun => "vlastné meno",
+        WordClass::Article => "člen",
+        WordClass::Phrase => "fráza",
+        WordClass::Idiom => "idióm",
+        WordClass::Prefix => "predpona",
+        WordClass::Suffix => "prípona",
+        WordClass::Abbreviation => "skratka",
+        WordClass::Unknown => "ostatné",
+    }
+}
+
+pub fn heading(heading: &Heading) -> &'static str {
+    match heading {
+        Heading::Author => "Autor",
+        Heading::SourceLanguage => "Zdrojový jazyk",
+        Heading::TargetLanguage => "Cieľový jazyk",
+        Heading::Records => "Počet hesiel",
+        Heading::Licence => "Licencia",
+        Heading::Date => "Dátum",
+        Heading::Sources => "Zdroje",
+        Heading::HowToUse => "Ako používať slovník",
+        Heading::Abbreviations => "Skratky a označenia",
+        Heading::WordClasses => "Slovné druhy",
+        Heading::Pronunciations => "Výslovnosť",
+    }
+}
+
+pub fn text(text: &Text) -> &'static str {
+    match text {
+        Text::UsageLookup => "Pri čítaní knihy označte slovo a zobrazí sa jeho heslo. Ohýbané tvary vedú k heslu základného tvaru.",
+        Text::UsageEntry => "Heslo začína hľadaným slovom a výslovnosťou, nasledujú slovné druhy. Pri každom slovnom druhu sú najprv uvedené preklady a potom číslované významy.",
+        Text::UnknownWordClass => "slovný druh nie je v zdroji uvedený",
+        Text::WikiPronunciation => "výslovnosť z Wikislovníka",
+    }
+}
+
+pub fn pronunciation_name(name: &str) -> Option<&'static str> {
+    match name {
+        "US" => Some("americká"),
+        "UK" | "GB" => Some("britská"),
+        _ => None,
+    }
+}
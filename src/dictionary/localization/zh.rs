@@ -0,0 +1,58 @@
+use crate::dictionary::{localization::{Heading, Text}, WordClass};
+
+pub fn word_class(word_class: &WordClass) -> &'static str {
+    match word_class {
+        WordClass::Noun => "名词",
+        WordClass::Verb => "动词",
+        WordClass::Adjective => "形容词",
+        WordClass::Adverb => "副词",
+        WordClass::Determiner => "限定词",
+        WordClass::Preposition => "介词",
+        WordClass::Pronoun => "代词",
+        WordClass::Conjunction => "连词",
+        WordClass::Interjection => "叹词",
+        WordClass::Numeral => "数词",
+        WordClass::Particle => "助词",
+        WordClass::ProperNoun => "专有名词",
+        WordClass::Article => "冠词",
+        WordClass::Phrase => "短语",
+        WordClass::Idiom => "习语",
+        WordClass::Prefix => "前缀",
+        WordClass::Suffix => "后缀",
+        WordClass::Abbreviation => "缩写",
+        WordClass::Unknown => "其他",
+    }
+}
+
+pub fn heading(heading: &Heading) -> &'static str {
+    match heading {
+        Heading::Author => "作者",
+        Heading::SourceLanguage => "源语言",
+        Heading::TargetLanguage => "目标语言",
+        Heading::Records => "词条数",
+        Heading::Licence => "许可协议",
+        Heading::Date => "日期",
+        Heading::Sources => "来源",
+        Heading::HowToUse => "使用说明",
+        Heading::Abbreviations => "缩写与标签",
+        Heading::WordClasses => "词类",
+        Heading::Pronunciations => "发音",
+    }
+}
+
+pub fn text(text: &Text) -> &'static str {
+    match text {
+        Text::UsageLookup => "阅读时选中一个词即可查看其词条。屈折形式会指向其基本形式的词条。",
+        Text::UsageEntry => "词条以词目和发音开头，随后是词类。每个词类下先列出译文，再列出编号的释义。",
+        Text::UnknownWordClass => "来源中未注明词类",
+        Text::WikiPronunciation => "来自维基词典的发音",
+    }
+}
+
+pub fn pronunciation_name(name: &str) -> Option<&'static str> {
+    match name {
+        "US" => Some("美式"),
+        "UK" | "GB" => Some("英式"),
+        _ => None,
+    }
+}
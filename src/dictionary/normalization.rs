@@ -0,0 +1,67 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::cli_config::{CliConfig, NormalizationForm};
+
+// typographic variants folded to ASCII apostrophe and hyphen
+const APOSTROPHES: [char; 6] = ['\u{2018}', '\u{2019}', '\u{201b}', '\u{2032}', '\u{02bc}', '`'];
+const DASHES: [char; 6] = ['\u{2010}', '\u{2011}', '\u{2012}', '\u{2013}', '\u{2014}', '\u{2212}'];
+
+// how headwords are unified before they are stored and compared
+#[derive(Debug, Clone)]
+pub struct Normalization {
+    form: NormalizationForm,
+    fold_punctuation: bool,
+    fold_diacritics: bool,
+}
+
+impl Normalization {
+    pub fn new(cfg: &CliConfig) -> Normalization {
+        Normalization {
+            form: cfg.normalization_form.clone(),
+            fold_punctuation: cfg.fold_punctuation,
+            fold_diacritics: cfg.fold_diacritics,
+        }
+    }
+
+    // headword in normalized form keeping its case
+    pub fn normalize(&self, word: &str) -> String {
+        let word = match self.form {
+            NormalizationForm::Nfc => word.nfc().collect::<String>(),
+            NormalizationForm::Nfkc => word.nfkc().collect::<String>(),
+            NormalizationForm::None => word.to_string(),
+        };
+        if !self.fold_punctuation {
+            return word;
+        }
+        word.chars()
+            .map(|c| match c {
+                c if APOSTROPHES.contains(&c) => '\'',
+                c if DASHES.contains(&c) => '-',
+                c => c,
+            })
+            .collect()
+    }
+
+    pub fn key(&self, word: &str) -> String {
+        self.normalize(word).to_lowercase()
+    }
+
+    // secondary key ignoring diacritics e.g. zena for žena, None if not enabled
+    pub fn folded_key(&self, key: &str) -> Option<String> {
+        if !self.fold_diacritics {
+            return None;
+        }
+        Some(key.nfd().filter(|c| !is_combining_mark(*c)).nfc().collect())
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization {
+            form: NormalizationForm::Nfc,
+            fold_punctuation: true,
+            fold_diacritics: false,
+        }
+    }
+}
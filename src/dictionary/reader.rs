@@ -1,5 +1,6 @@
 pub mod gnu_fdl;
 pub mod pronunciation;
+pub mod unimorph;
 pub mod wiki;
 
 use std::error::Error;
@@ -2,6 +2,9 @@ use std::error::Error;
 
 use crate::dictionary::{Dictionary, Meaning, WordClass};
 
+// source id of translations from the GNU/FDL dictionary
+const SOURCE: &str = "gnu-fdl";
+
 pub fn read_czech(dict: &mut Dictionary, path: &str) -> Result<(), Box<dyn Error>> {
     let lines = super::read_tab_file(path)?;
 
@@ -18,7 +21,7 @@ pub fn read_czech(dict: &mut Dictionary, path: &str) -> Result<(), Box<dyn Error
         }
 
         let mut meaning = Meaning::new("");
-        meaning.add_translation(translation);
+        meaning.add_translation(translation, SOURCE);
 
         let word_class = match line[2].trim() {
             "n:" => WordClass::Noun,
@@ -27,6 +30,17 @@ pub fn read_czech(dict: &mut Dictionary, path: &str) -> Result<(), Box<dyn Error
             "adj:" => WordClass::Adjective,
             "pron:" => WordClass::Pronoun,
             "prep:" => WordClass::Preposition,
+            "conj:" => WordClass::Conjunction,
+            "interj:" => WordClass::Interjection,
+            "num:" => WordClass::Numeral,
+            "part:" => WordClass::Particle,
+            "art:" => WordClass::Article,
+            "pn:" => WordClass::ProperNoun,
+            "phr:" => WordClass::Phrase,
+            "idiom:" => WordClass::Idiom,
+            "pref:" => WordClass::Prefix,
+            "suf:" | "suff:" => WordClass::Suffix,
+            "abbr:" => WordClass::Abbreviation,
             _ => WordClass::Unknown
         };
 
@@ -0,0 +1,70 @@
+use std::error::Error;
+
+use crate::dictionary::{Dictionary, WordClass};
+
+// UniMorph paradigm table: lemma, inflected form and feature bundle e.g. N;GEN;PL
+pub fn read_unimorph(dict: &mut Dictionary, path: &str) -> Result<(), Box<dyn Error>> {
+    let lines = super::read_tab_file(path)?;
+
+    for line in lines.iter().filter(|line| line.len() > 2) {
+        let lemma = line[0].trim();
+        let form = line[1].trim();
+        if lemma.is_empty() || form.is_empty() || lemma == form {
+            continue;
+        }
+
+        if let Some(word_class) = word_class(&line[2]) {
+            dict.add_paradigm(lemma, &word_class, &[form.to_string()]);
+        }
+    }
+
+    Ok(())
+}
+
+fn word_class(features: &str) -> Option<WordClass> {
+    features.trim().split(';').find_map(|feature| match feature {
+        "N" => Some(WordClass::Noun),
+        "V" | "V.PTCP" | "V.CVB" | "V.MSDR" => Some(WordClass::Verb),
+        "ADJ" => Some(WordClass::Adjective),
+        "ADV" => Some(WordClass::Adverb),
+        "ADP" => Some(WordClass::Preposition),
+        "DET" => Some(WordClass::Determiner),
+        "PRO" => Some(WordClass::Pronoun),
+        "CONJ" => Some(WordClass::Conjunction),
+        "INTJ" => Some(WordClass::Interjection),
+        "NUM" => Some(WordClass::Numeral),
+        "PART" => Some(WordClass::Particle),
+        "PROPN" => Some(WordClass::ProperNoun),
+        "ART" => Some(WordClass::Article),
+        _ => None,
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_of_speech() {
+        assert_eq!(word_class("N;GEN;PL"), Some(WordClass::Noun));
+        assert_eq!(word_class("V.PTCP;PST"), Some(WordClass::Verb));
+        assert_eq!(word_class("IND;PRS;3;SG;V"), Some(WordClass::Verb));
+        assert_eq!(word_class("ADJ;CMPR"), Some(WordClass::Adjective));
+        assert_eq!(word_class("PST;PL"), None);
+    }
+
+    #[test]
+    fn paradigms_by_word_class() {
+        let path = std::env::temp_dir().join(format!("unimorph-test-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, "child\tchildren\tN;PL\nchild\tchild\tN;SG\nrun\tran\tV;PST\nrun\truns\tNFIN\n").unwrap();
+        let mut dict = Dictionary::new("en", "cs", "test", "test");
+        read_unimorph(&mut dict, path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(dict.paradigms["child"][&WordClass::Noun], ["children"]);
+        assert_eq!(dict.paradigms["run"][&WordClass::Verb], ["ran"]);
+        assert_eq!(dict.paradigms["run"].len(), 1);
+    }
+}
@@ -1,5 +1,7 @@
 extern crate bzip2;
 
+mod templates;
+
 use regex::Regex;
 
 use quick_xml::events::Event;
@@ -7,7 +9,10 @@ use quick_xml::reader::Reader;
 
 use std::error::Error;
 
-use crate::dictionary::{Dictionary, Meaning, WordClass};
+use crate::dictionary::{language::en::English, Dictionary, Meaning, WordClass};
+
+// source id of translations and pronunciations from wiktionary
+const SOURCE: &str = "wiki";
 
 #[derive(Debug)]
 struct Page {
@@ -28,12 +33,39 @@ enum State {
     None, Page, Title, Content,
 }
 
+// form-of templates of target language words e.g. {{inflection of|cs|žena||gen|s}}
+const FORM_OF_TEMPLATES: [&str; 16] = [
+    "inflection of", "infl of", "plural of", "past participle of", "present participle of",
+    "past tense of", "comparative of", "superlative of", "feminine of", "feminine singular of",
+    "feminine plural of", "masculine plural of", "neuter singular of", "gerund of", "participle of",
+    "verb form of",
+];
+
+// slots of inflection tables holding forms, positional ones by their number, other parameters are stems and classes
+const FORM_TABLES: [(&str, &[&str]); 3] = [
+    // nominative, genitive, dative, accusative, vocative, locative and instrumental, singular and plural
+    ("cs-decl-noun", &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14"]),
+    ("cs-conj", &[
+        "pres_1s", "pres_2s", "pres_3s", "pres_1p", "pres_2p", "pres_3p",
+        "fut_1s", "fut_2s", "fut_3s", "fut_1p", "fut_2p", "fut_3p",
+        "imp_2s", "imp_1p", "imp_2p", "lpart_m", "lpart_f", "lpart_n", "lpart_mp", "lpart_fp",
+    ]),
+    ("de-conj", &[
+        "pres_1s", "pres_2s", "pres_3s", "pres_1p", "pres_2p", "pres_3p",
+        "pret_1s", "pret_2s", "pret_3s", "pret_1p", "pret_2p", "pret_3p",
+        "subi_1s", "subi_2s", "subi_3s", "subi_1p", "subi_2p", "subi_3p",
+        "subii_1s", "subii_2s", "subii_3s", "subii_1p", "subii_2p", "subii_3p",
+        "imp_2s", "imp_2p", "pres_part", "perf_part",
+    ]),
+];
+
 struct Re {
     data: Regex,
     translations_title: Regex,
     language: Regex,
     translation_name: Regex,
     prefix: Regex,
+    target_language: Regex,
 }
 
 impl Re {
@@ -46,6 +78,7 @@ impl Re {
             language: Regex::new(r"^==([^=]+)==$").unwrap(),
             translation_name: Regex::new(&name).unwrap(),
             prefix: Regex::new(&prefix).unwrap(),
+            target_language: Regex::new(&format!("^=={}==$", regex::escape(lang_prefix))).unwrap(),
         }
     }
 }
@@ -63,6 +96,7 @@ pub fn read_wiki(dict: &mut Dictionary, path: &str, prefix: &str) -> Result<(),
     let mut page = Page::empty();
     let mut state = State::None;
     let re = Re::new(prefix);
+    let english = English::new();
 
     loop {
         match reader.read_event_into(&mut buf) {
@@ -96,7 +130,7 @@ pub fn read_wiki(dict: &mut Dictionary, path: &str, prefix: &str) -> Result<(),
                     b"page" => {
                         state = State::None;
                         if !page.title.contains(":") {
-                            read_wiki_page(dict, &page, &re);
+                            read_wiki_page(dict, &page, &re, &english);
                         }
                     },
 
@@ -137,7 +171,7 @@ pub fn read_wiki(dict: &mut Dictionary, path: &str, prefix: &str) -> Result<(),
     Ok(())
 }
 
-fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re) {
+fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re, english: &English) {
     let mut headword = page.title.trim();
     if let Some(captures) = re.translations_title.captures(headword) {
         headword = captures.get(1).unwrap().as_str();
@@ -148,13 +182,30 @@ fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re) {
     let mut current_language = String::from("");
     let mut current_translation_name = String::from("");
 
+    let mut in_target_language = false;
+    // inflection tables usually span several lines until the closing }}
+    let mut template = String::new();
+
     for line in page.content.lines() {
         if let Some(captures) = re.language.captures(line) {
             current_language = captures.get(1).unwrap().as_str().to_lowercase();
+            in_target_language = re.target_language.is_match(line);
+            template.clear();
+        }
+
+        if in_target_language {
+            template.push_str(line);
+            if template.matches("{{").count() > template.matches("}}").count() {
+                template.push(' ');
+                continue;
+            }
+            read_target_language_forms(dict, headword, &template, re);
+            template.clear();
+            continue;
         }
 
         if !current_language.is_empty() && current_language != "english" {
-            break;
+            continue;
         }
         
         if let Some(captures) = re.translation_name.captures(line) {
@@ -180,7 +231,7 @@ fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re) {
                         if !pronunciation.starts_with("/") {
                             continue;
                         }
-                        dict.add_pronunciation(headword, "wiki", pronunciation);
+                        dict.add_pronunciation(headword, SOURCE, pronunciation);
                     }
                 },
 
@@ -208,13 +259,38 @@ fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re) {
                     }
                 }
 
-                "en-noun" => current_word_class = WordClass::Noun,
+                "en-noun" => {
+                    current_word_class = WordClass::Noun;
+                    add_template_forms(dict, headword, &current_word_class, templates::noun_forms(english, headword, &parts[1..]));
+                },
                 "en-pron" => current_word_class = WordClass::Pronoun,
-                "en-adv" => current_word_class = WordClass::Adverb,
+                "en-adv" => {
+                    current_word_class = WordClass::Adverb;
+                    add_template_forms(dict, headword, &current_word_class, templates::comparison_forms(english, headword, &parts[1..]));
+                },
                 "en-det" => current_word_class = WordClass::Determiner,
-                "en-con" => current_word_class = WordClass::LinkingWord,
-                "en-verb" => current_word_class = WordClass::Verb,
-                "en-adj" => current_word_class = WordClass::Adjective,
+                "en-con" => current_word_class = WordClass::Conjunction,
+                "en-intj" => current_word_class = WordClass::Interjection,
+                "en-proper noun" | "en-prop" => current_word_class = WordClass::ProperNoun,
+                "en-phrase" => current_word_class = WordClass::Phrase,
+                "en-prefix" => current_word_class = WordClass::Prefix,
+                "en-suffix" => current_word_class = WordClass::Suffix,
+                "en-abbr" | "en-initialism" | "en-acronym" => current_word_class = WordClass::Abbreviation,
+                "en-num" | "en-card num" | "en-ord num" => current_word_class = WordClass::Numeral,
+                "en-part" | "en-particle" => current_word_class = WordClass::Particle,
+                "en-art" => current_word_class = WordClass::Article,
+                "en-idiom" => current_word_class = WordClass::Idiom,
+                "head" if parts.get(1).map(|language| language.trim()) == Some("en") => {
+                    current_word_class = head_word_class(parts.get(2).map_or("", |pos| pos.trim()));
+                },
+                "en-verb" => {
+                    current_word_class = WordClass::Verb;
+                    add_template_forms(dict, headword, &current_word_class, templates::verb_forms(english, headword, &parts[1..]));
+                },
+                "en-adj" => {
+                    current_word_class = WordClass::Adjective;
+                    add_template_forms(dict, headword, &current_word_class, templates::comparison_forms(english, headword, &parts[1..]));
+                },
                 "en-prep" => current_word_class = WordClass::Preposition,
 
                 _ => if parts.len() > 2 && re.prefix.is_match(line) {
@@ -222,7 +298,12 @@ fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re) {
                         continue;
                     }
                     let translation = parts[2].trim();
-                    current_meaning.add_translation(translation);
+                    current_meaning.add_translation(translation, SOURCE);
+                    if let Some(gender) = parts.get(3).map(|part| part.trim()) {
+                        if !gender.is_empty() && !gender.contains('=') && gender.starts_with(['m', 'f', 'n']) && gender.len() <= 3 {
+                            dict.add_translation_gender(translation, gender);
+                        }
+                    }
                 }
             }
         }
@@ -232,3 +313,140 @@ fn read_wiki_page(dict: &mut Dictionary, page: &Page, re: &Re) {
         dict.add_meaning(headword, &current_word_class, &current_meaning);
     }
 }
+
+// part of speech of {{head|en|...}} template
+fn head_word_class(part_of_speech: &str) -> WordClass {
+    match part_of_speech {
+        "noun" => WordClass::Noun,
+        "proper noun" => WordClass::ProperNoun,
+        "verb" => WordClass::Verb,
+        "adjective" => WordClass::Adjective,
+        "adverb" => WordClass::Adverb,
+        "pronoun" => WordClass::Pronoun,
+        "preposition" => WordClass::Preposition,
+        "determiner" => WordClass::Determiner,
+        "conjunction" => WordClass::Conjunction,
+        "interjection" => WordClass::Interjection,
+        "numeral" | "number" => WordClass::Numeral,
+        "particle" => WordClass::Particle,
+        "article" => WordClass::Article,
+        "phrase" | "proverb" | "prepositional phrase" => WordClass::Phrase,
+        "idiom" => WordClass::Idiom,
+        "prefix" => WordClass::Prefix,
+        "suffix" => WordClass::Suffix,
+        "abbreviation" | "initialism" | "acronym" => WordClass::Abbreviation,
+        _ => WordClass::Unknown,
+    }
+}
+
+// forms of target language words used as inflections of reversed dictionary headwords
+fn read_target_language_forms(dict: &mut Dictionary, title: &str, line: &str, re: &Re) {
+    for captures in re.data.captures_iter(line) {
+        let parts = captures.get(1).unwrap().as_str().split('|').map(|part| part.trim()).collect::<Vec<_>>();
+        let control = parts[0];
+
+        if FORM_OF_TEMPLATES.contains(&control) {
+            // the language is either the first parameter or given by lang=
+            let mut positional = parts[1..].iter().filter(|part| !part.contains('='));
+            let language = match parts.iter().find_map(|part| part.strip_prefix("lang=")) {
+                Some(language) => Some(language),
+                None => positional.next().copied(),
+            };
+            if language != Some(dict.target_language.as_str()) {
+                continue;
+            }
+            if let Some(lemma) = positional.next().map(|lemma| lemma.replace("[[", "").replace("]]", "")) {
+                if !lemma.is_empty() && lemma != title {
+                    dict.add_translation_form(&lemma, title);
+                }
+            }
+        } else if let Some((_, slots)) = FORM_TABLES.iter().find(|(template, _)| *template == control) {
+            for form in table_forms(&parts[1..], slots) {
+                if form != title {
+                    dict.add_translation_form(title, &form);
+                }
+            }
+        }
+    }
+}
+
+// forms in the slots of a table, a slot may list alternatives: hradě / hradu
+fn table_forms(params: &[&str], slots: &[&str]) -> Vec<String> {
+    let mut position = 0;
+    let mut forms = vec![];
+    for param in params {
+        let (name, value) = match param.split_once('=') {
+            Some((name, value)) => (name.trim().to_string(), value),
+            None => {
+                position += 1;
+                (position.to_string(), *param)
+            },
+        };
+        if !slots.contains(&name.as_str()) {
+            continue;
+        }
+        for form in value.replace("[[", "").replace("]]", "").split(['/', ',']) {
+            let form = form.trim();
+            if !form.is_empty() && form != "-" && form.chars().all(|c| c.is_alphabetic() || c == ' ') {
+                forms.push(form.to_string());
+            }
+        }
+    }
+    forms
+}
+
+// forms from head templates replace generated inflections of the word class
+fn add_template_forms(dict: &mut Dictionary, headword: &str, word_class: &WordClass, forms: Option<Vec<String>>) {
+    if let Some(forms) = forms {
+        let forms = forms
+            .into_iter()
+            .filter(|form| form != headword && !form.contains(['*', '<', '>', '?', '!']))
+            .collect::<Vec<_>>();
+        dict.add_paradigm(headword, word_class, &forms);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_forms(title: &str, line: &str) -> Vec<String> {
+        let mut dict = Dictionary::new("en", "cs", "test", "test");
+        read_target_language_forms(&mut dict, title, line, &Re::new("Czech"));
+        let mut forms = dict.translation_forms.remove(title).unwrap_or_default().into_iter().collect::<Vec<_>>();
+        forms.sort();
+        forms
+    }
+
+    #[test]
+    fn declension_table_forms() {
+        let forms = read_forms("hrad", "{{cs-decl-noun|hrad|hrady|hradu|hradů|hradu|hradům|hrad|hrady|hrade|hrady|hradě / hradu|hradech|hradem|hrady}}");
+        assert_eq!(forms, ["hrade", "hradech", "hradem", "hradu", "hrady", "hradě", "hradů", "hradům"]);
+    }
+
+    #[test]
+    fn conjugation_table_forms() {
+        let forms = read_forms("psát", "{{cs-conj|p|impf|pres_1s=píšu / píši|pres_3s=píše|pres_3p=píšou, píší|lpart_m=psal|ppp=psán}}");
+        assert_eq!(forms, ["psal", "píše", "píši", "píšou", "píšu", "píší"]);
+    }
+
+    #[test]
+    fn multi_line_tables() {
+        let content = "==English==\n===Noun===\n{{en-noun}}\n\n==German==\n===Verb===\n{{de-verb}}\n\n====Conjugation====\n{{de-conj|<haben>\n|pres_2s=schreibst\n|pres_3s=schreibt\n|pret_1s=schrieb\n|perf_part=geschrieben\n|aux=haben}}\n";
+        let page = Page { title: "schreiben".to_string(), content: content.to_string() };
+        let mut dict = Dictionary::new("en", "de", "test", "test");
+        read_wiki_page(&mut dict, &page, &Re::new("German"), &English::new());
+        let mut forms = dict.translation_forms.remove("schreiben").unwrap_or_default().into_iter().collect::<Vec<_>>();
+        forms.sort();
+        assert_eq!(forms, ["geschrieben", "schreibst", "schreibt", "schrieb"]);
+    }
+
+    #[test]
+    fn form_of_template() {
+        assert_eq!(read_forms("žena", "# {{inflection of|cs|žena||gen|s}}"), Vec::<String>::new());
+        let mut dict = Dictionary::new("en", "cs", "test", "test");
+        read_target_language_forms(&mut dict, "ženy", "# {{inflection of|cs|žena||gen|s}}", &Re::new("Czech"));
+        assert!(dict.translation_forms["žena"].contains("ženy"));
+    }
+}
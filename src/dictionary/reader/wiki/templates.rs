@@ -0,0 +1,148 @@
+use crate::dictionary::language::en::English;
+
+// forms given by English head templates, None when the template gives no data
+// e.g. {{en-noun|children}}, {{en-verb|goes|going|went|gone}}, {{en-adj|better|sup=best}}
+
+pub fn noun_forms(english: &English, headword: &str, params: &[&str]) -> Option<Vec<String>> {
+    let positional = positional(params);
+    if positional.is_empty() {
+        return None;
+    }
+
+    let mut forms = vec![];
+    for value in positional.iter() {
+        match value.as_str() {
+            // uncountable, plural unknown or not attested
+            "-" | "?" | "!" => (),
+            // countable and uncountable with default plural
+            "~" if positional.len() == 1 => forms.push(english.add_s(headword)),
+            "~" => (),
+            "+" | "s" => forms.push(english.add_s(headword)),
+            "es" => forms.push(format!("{}es", headword)),
+            form => forms.push(form.to_string()),
+        }
+    }
+
+    Some(forms)
+}
+
+pub fn verb_forms(english: &English, headword: &str, params: &[&str]) -> Option<Vec<String>> {
+    let positional = positional(params);
+    if positional.is_empty() {
+        return None;
+    }
+
+    // older stem and ending syntax: {{en-verb|cr|ies}}, {{en-verb|stop|p}}, {{en-verb|tak|ing|took|taken}}
+    if let (Some(stem), Some(ending)) = (positional.first(), positional.get(1)) {
+        let single_consonant = ending.chars().count() == 1 && !"aeiou".contains(ending.as_str());
+        if headword.starts_with(stem.as_str()) && (["es", "ies", "d", "ing"].contains(&ending.as_str()) || single_consonant) {
+            let forms = match ending.as_str() {
+                "ies" => vec![format!("{}ies", stem), format!("{}ying", stem), format!("{}ied", stem)],
+                "ing" => {
+                    let mut forms = vec![format!("{}es", stem), format!("{}ing", stem)];
+                    match positional.get(2) {
+                        Some(_) => forms.extend(positional.iter().skip(2).cloned()),
+                        None => forms.push(format!("{}ed", stem)),
+                    }
+                    forms
+                },
+                "es" | "d" => vec![format!("{}es", stem), format!("{}ing", stem), format!("{}ed", stem)],
+                consonant => vec![
+                    format!("{}s", headword),
+                    format!("{}{}ing", stem, consonant),
+                    format!("{}{}ed", stem, consonant),
+                ],
+            };
+            return Some(forms);
+        }
+    }
+
+    // third person singular, present participle, past and past participle
+    // where ++ alone doubles the final consonant in all forms: {{en-verb|++}}
+    let default = if positional.len() == 1 && positional[0] == "++" { "++" } else { "+" };
+    let mut forms = vec![];
+    for slot in 0..4 {
+        let value = positional.get(slot).map(|value| value.as_str()).unwrap_or(default);
+        match (slot, value) {
+            (_, "-") => (),
+            (0, "+" | "++") => forms.push(english.add_s(headword)),
+            (0, "es") => forms.push(format!("{}es", headword)),
+            (1, "+") => forms.push(english.add_ing(headword)),
+            (1, "++") => forms.push(format!("{}{}ing", headword, last_char(headword))),
+            (2, "+") => forms.push(english.add_ed(headword)),
+            (2, "++") => forms.push(format!("{}{}ed", headword, last_char(headword))),
+            // past participle is the same as past by default
+            (3, "+" | "++") => (),
+            (_, form) => forms.push(form.to_string()),
+        }
+    }
+
+    Some(forms)
+}
+
+// comparatives and superlatives of en-adj and en-adv
+pub fn comparison_forms(english: &English, headword: &str, params: &[&str]) -> Option<Vec<String>> {
+    let positional = positional(params);
+    if positional.is_empty() {
+        return None;
+    }
+
+    // older comparative and superlative syntax: {{en-adj|better|best}}
+    if let [comparative, superlative] = positional.as_slice() {
+        if comparative.ends_with('r') && superlative.ends_with("st") {
+            return Some(vec![comparative.clone(), superlative.clone()]);
+        }
+    }
+
+    let mut forms = vec![];
+    for (index, value) in positional.iter().enumerate() {
+        match value.as_str() {
+            // not comparable or comparable with more and most
+            "-" | "~" | "+" | "more" | "?" => (),
+            "er" => {
+                let stem = english.comparison_stem(headword);
+                forms.push(format!("{}er", stem));
+                forms.push(format!("{}est", stem));
+            },
+            comparative if comparative.starts_with("more ") => (),
+            comparative => {
+                forms.push(comparative.to_string());
+                let name = if index == 0 { "sup".to_string() } else { format!("sup{}", index + 1) };
+                match named(params, &name) {
+                    Some(superlative) => forms.push(superlative),
+                    None => if let Some(stem) = comparative.strip_suffix("er") {
+                        forms.push(format!("{}est", stem));
+                    },
+                }
+            },
+        }
+    }
+
+    Some(forms)
+}
+
+// positional parameters without links: [[word]] -> word
+fn positional(params: &[&str]) -> Vec<String> {
+    params
+        .iter()
+        .map(|param| param.trim())
+        .filter(|param| !param.contains('='))
+        .map(strip_links)
+        .take_while(|param| !param.is_empty())
+        .collect()
+}
+
+fn named(params: &[&str], name: &str) -> Option<String> {
+    params.iter().find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        if key.trim() == name && !value.trim().is_empty() { Some(strip_links(value.trim())) } else { None }
+    })
+}
+
+fn strip_links(text: &str) -> String {
+    text.replace("[[", "").replace("]]", "")
+}
+
+fn last_char(word: &str) -> char {
+    word.chars().last().unwrap_or_default()
+}
@@ -1,10 +1,101 @@
+mod cover;
+pub mod dictd;
+pub mod epub;
+pub mod json;
 pub mod kindle;
+mod validator;
 
-fn escape_xml(input: &str) -> String {
+use std::error::Error;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::dictionary::localization::{heading, Heading};
+use crate::dictionary::Dictionary;
+
+pub fn escape_xml(input: &str) -> String {
     input
         .replace("&", "&amp;")
         .replace("<", "&lt;")
         .replace(">", "&gt;")
         .replace("'", "&apos;")
         .replace("\"", "&quot;")
-}
\ No newline at end of file
+}
+
+fn escape_json(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(format!("\\u{:04x}", c as u32).as_str()),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// make sure the output directory exists and it is safe to write into it
+fn prepare_output_dir(output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
+    let output = fs::metadata(output_path);
+    match output {
+        Ok(metadata) => {
+            if metadata.is_file() {
+                Err(format!("{} is a file, a directory expected.", output_path))?;
+            } else if metadata.is_dir() && !force {
+                Err(format!("{} is an existing directory, use -f to force.", output_path))?;
+            }
+        },
+
+        Err(_) => {
+            fs::create_dir_all(output_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+// stable UUID of the dictionary derived from its title, languages and author by FNV-1a hash
+fn identifier(dict: &Dictionary) -> String {
+    let name = format!("{}\t{}\t{}\t{}", dict.title, dict.source_language, dict.target_language, dict.author);
+    let mut hash: u128 = 0x6c62272e07bb014262b821756295c58d;
+    for byte in name.bytes() {
+        hash ^= byte as u128;
+        hash = hash.wrapping_mul(0x0000000001000000000000000000013b);
+    }
+    // version 8 (custom) and RFC 4122 variant
+    hash = (hash & !(0xf << 76)) | (0x8 << 76);
+    hash = (hash & !(0x3 << 62)) | (0x2 << 62);
+    let hex = format!("{:032x}", hash);
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+// short description of the dictionary in the language of labels
+fn description(dict: &Dictionary) -> String {
+    let language = dict.ui_language.as_str();
+    format!(
+        "{}. {}: {}, {}: {}, {}: {}.",
+        dict.title,
+        heading(language, &Heading::SourceLanguage), dict.source_language,
+        heading(language, &Heading::TargetLanguage), dict.target_language,
+        heading(language, &Heading::Records), dict.non_empty_len(),
+    )
+}
+
+// current date as YYYY-MM-DD
+fn today() -> String {
+    let days = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() / 86_400) as i64;
+    // days to civil date conversion from http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
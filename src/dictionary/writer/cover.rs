@@ -0,0 +1,208 @@
+mod font;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+
+use flate2::write::ZlibEncoder;
+use flate2::{Compression, Crc};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::dictionary::Dictionary;
+
+const WIDTH: usize = 600;
+const HEIGHT: usize = 800;
+const MARGIN: usize = 50;
+const BACKGROUND: [u8; 3] = [0x1f, 0x3a, 0x5f];
+const ACCENT: [u8; 3] = [0xe8, 0xa8, 0x38];
+const FOREGROUND: [u8; 3] = [0xff, 0xff, 0xff];
+const GLYPH_HEIGHT: usize = 32;
+// title is made smaller until it fits into the area between the bands
+const TITLE_SIZES: [usize; 4] = [72, 56, 44, 34];
+const MAX_TITLE_LINES: usize = 5;
+
+pub struct Glyph {
+    advance: usize,
+    rows: [u32; GLYPH_HEIGHT],
+}
+
+struct Canvas {
+    glyphs: HashMap<char, Glyph>,
+    pixels: Vec<u8>,
+}
+
+// writes the user's image or a generated one into the output directory, returns its file name and media type
+pub fn create_cover(dict: &Dictionary, output_path: &str) -> Result<(String, &'static str), Box<dyn Error>> {
+    if let Some(cover_image) = &dict.cover_image {
+        let extension = cover_image.rsplit('.').next().unwrap_or("").to_lowercase();
+        let media_type = match extension.as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            _ => Err(format!("Unsupported cover image {}, png, jpg or gif expected.", cover_image))?,
+        };
+        let file_name = format!("dict.{}", extension);
+        fs::copy(cover_image, format!("{}/{}", output_path, file_name))
+            .map_err(|err| format!("Cannot copy cover image {}: {}", cover_image, err))?;
+        return Ok((file_name, media_type));
+    }
+
+    fs::write(format!("{}/dict.png", output_path), render_cover(dict)?)?;
+    Ok(("dict.png".to_string(), "image/png"))
+}
+
+// title between two bands, language pair below and author at the bottom
+fn render_cover(dict: &Dictionary) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut canvas = Canvas::new();
+    canvas.fill(0, 0, WIDTH, HEIGHT, BACKGROUND);
+    canvas.fill(0, 140, WIDTH, 8, ACCENT);
+    canvas.fill(0, 600, WIDTH, 8, ACCENT);
+
+    let (size, lines) = canvas.wrap_title(&dict.title);
+    let line_height = size * 5 / 4;
+    let mut y = 148 + 452_usize.saturating_sub(lines.len() * line_height) / 2;
+    for line in lines {
+        canvas.draw_centered(&line, y, size);
+        y += line_height;
+    }
+
+    let languages = format!("{} – {}", dict.source_language.to_uppercase(), dict.target_language.to_uppercase());
+    canvas.draw_centered(&languages, 640, 40);
+    if !dict.author.is_empty() {
+        canvas.draw_centered(&dict.author, 720, 30);
+    }
+
+    encode_png(WIDTH, HEIGHT, &canvas.pixels)
+}
+
+impl Canvas {
+    fn new() -> Canvas {
+        Canvas {
+            glyphs: font::build(),
+            pixels: vec![0; WIDTH * HEIGHT * 3],
+        }
+    }
+
+    fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, color: [u8; 3]) {
+        for row in y..(y + height).min(HEIGHT) {
+            for column in x..(x + width).min(WIDTH) {
+                let offset = (row * WIDTH + column) * 3;
+                self.pixels[offset..offset + 3].copy_from_slice(&color);
+            }
+        }
+    }
+
+    // letters without glyph are drawn without diacritics or as question mark
+    fn glyph(&self, c: char) -> &Glyph {
+        self.glyphs
+            .get(&c)
+            .or_else(|| c.to_string().nfd().find(|c| !is_combining_mark(*c)).and_then(|base| self.glyphs.get(&base)))
+            .unwrap_or_else(|| self.glyphs.get(&'?').unwrap())
+    }
+
+    fn text_width(&self, text: &str, size: usize) -> usize {
+        text.chars().map(|c| self.glyph(c).advance * size / GLYPH_HEIGHT).sum()
+    }
+
+    // the largest size at which the title fits, words are wrapped into lines
+    fn wrap_title(&self, title: &str) -> (usize, Vec<String>) {
+        let max_width = WIDTH - 2 * MARGIN;
+        let mut lines = vec![];
+        for size in TITLE_SIZES {
+            lines = vec![];
+            let mut line = String::new();
+            for word in title.split_whitespace() {
+                let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+                if self.text_width(&candidate, size) <= max_width || line.is_empty() {
+                    line = candidate;
+                } else {
+                    lines.push(line);
+                    line = word.to_string();
+                }
+            }
+            if !line.is_empty() {
+                lines.push(line);
+            }
+            let fits = lines.iter().all(|line| self.text_width(line, size) <= max_width);
+            if fits && lines.len() <= MAX_TITLE_LINES {
+                return (size, lines);
+            }
+        }
+        lines.truncate(MAX_TITLE_LINES);
+        (TITLE_SIZES[TITLE_SIZES.len() - 1], lines)
+    }
+
+    fn draw_centered(&mut self, text: &str, y: usize, size: usize) {
+        let width = self.text_width(text, size);
+        let mut x = WIDTH.saturating_sub(width) / 2;
+        for c in text.chars() {
+            let glyph = self.glyph(c);
+            let (advance, rows) = (glyph.advance * size / GLYPH_HEIGHT, glyph.rows);
+            self.draw_glyph(&rows, x, y, size);
+            x += advance;
+        }
+    }
+
+    // scales the glyph to the size with 4x4 samples per pixel for smooth edges
+    fn draw_glyph(&mut self, rows: &[u32; GLYPH_HEIGHT], x: usize, y: usize, size: usize) {
+        const SAMPLES: usize = 4;
+        for py in 0..size {
+            for px in 0..size {
+                let mut hits = 0;
+                for sy in 0..SAMPLES {
+                    for sx in 0..SAMPLES {
+                        let gy = ((py * SAMPLES + sy) * GLYPH_HEIGHT) / (size * SAMPLES);
+                        let gx = ((px * SAMPLES + sx) * GLYPH_HEIGHT) / (size * SAMPLES);
+                        if rows[gy] >> gx & 1 == 1 {
+                            hits += 1;
+                        }
+                    }
+                }
+                if hits == 0 || x + px >= WIDTH || y + py >= HEIGHT {
+                    continue;
+                }
+                let offset = ((y + py) * WIDTH + x + px) * 3;
+                for (channel, &foreground) in FOREGROUND.iter().enumerate() {
+                    let background = self.pixels[offset + channel] as usize;
+                    let color = (foreground as usize * hits + background * (SAMPLES * SAMPLES - hits)) / (SAMPLES * SAMPLES);
+                    self.pixels[offset + channel] = color as u8;
+                }
+            }
+        }
+    }
+}
+
+// 8-bit RGB image without filtering
+fn encode_png(width: usize, height: usize, pixels: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut raw = Vec::with_capacity((width * 3 + 1) * height);
+    for row in pixels.chunks(width * 3) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(&raw)?;
+    let data = encoder.finish()?;
+
+    let mut header = vec![];
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &data);
+    write_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let mut crc = Crc::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    png.extend_from_slice(&crc.sum().to_be_bytes());
+}
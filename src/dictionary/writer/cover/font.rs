@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use super::Glyph;
+
+// DejaVu Sans Bold (Bitstream Vera license) rasterized to 32 pixels, the leftmost pixel of a row is the lowest bit
+pub fn build() -> HashMap<char, Glyph> {
+    let dataset = vec![
+        (' ', 9, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('!', 12, [0x0, 0x0, 0x0, 0x0, 0x0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0x60, 0x0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('"', 13, [0x0, 0x0, 0x0, 0x0, 0x0, 0x718, 0x71c, 0x71c, 0x71c, 0x71c, 0x71c, 0x71c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('#', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x18600, 0x18700, 0x1c700, 0x1c700, 0x1c300, 0xffff8, 0xffff8, 0xffff8, 0xe180, 0x71c0, 0x71c0, 0x3fffc, 0x3fffc, 0x3fffc, 0x38e0, 0x38e0, 0x3860, 0x1870, 0x1c70, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('$', 18, [0x0, 0x0, 0x0, 0x0, 0x300, 0x300, 0x300, 0xfc0, 0x7ff0, 0x7ff8, 0x637c, 0x33c, 0x33c, 0x3fc, 0x1ff8, 0x7ff0, 0xffe0, 0xff00, 0xf300, 0xf300, 0xf31c, 0x7ffc, 0x7ffc, 0xfe0, 0x300, 0x300, 0x300, 0x300, 0x0, 0x0, 0x0, 0x0]),
+        ('%', 26, [0x0, 0x0, 0x0, 0x0, 0x0, 0xe01f8, 0x703fc, 0x7039e, 0x3870e, 0x1870e, 0x1c70e, 0xe78e, 0xe39e, 0x73fc, 0x3c30f0, 0xff3800, 0xe71c00, 0x1c39c00, 0x1c38e00, 0x1c38600, 0x1c38700, 0x1e78380, 0xff0380, 0x7e01c0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('&', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f80, 0x7fc0, 0x7fe0, 0x41e0, 0x1f0, 0x1e0, 0x3e0, 0x7e0, 0xf0ff0, 0xf1ff8, 0xf3f7c, 0xf7e3c, 0x7fc3c, 0x7f83c, 0x3f07c, 0x3f0fc, 0x7fff8, 0xffff0, 0x1f1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('\'', 8, [0x0, 0x0, 0x0, 0x0, 0x0, 0x18, 0x1c, 0x1c, 0x1c, 0x1c, 0x1c, 0x1c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('(', 12, [0x0, 0x0, 0x0, 0x0, 0x1c0, 0x1e0, 0x1e0, 0xf0, 0xf0, 0xf8, 0x78, 0x78, 0x78, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x78, 0x78, 0x78, 0xf0, 0xf0, 0xf0, 0x1e0, 0x1c0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        (')', 12, [0x0, 0x0, 0x0, 0x0, 0x38, 0x38, 0x78, 0xf0, 0xf0, 0xf0, 0x1e0, 0x1e0, 0x1e0, 0x1e0, 0x1e0, 0x3e0, 0x3e0, 0x1e0, 0x1e0, 0x1e0, 0x1e0, 0x1f0, 0xf0, 0xf0, 0x78, 0x78, 0x3c, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('*', 13, [0x0, 0x0, 0x0, 0x0, 0x0, 0xc0, 0xc0, 0x8c6, 0x1ede, 0x7fc, 0x1f0, 0x3f8, 0xffe, 0x1cce, 0xc0, 0xc0, 0xc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('+', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xe00, 0xe00, 0xe00, 0xe00, 0xe00, 0xe00, 0x7fff8, 0x7fff8, 0x7fff8, 0x1e00, 0xe00, 0xe00, 0xe00, 0xe00, 0xe00, 0xe00, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        (',', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x7c, 0x3c, 0x1c, 0x1c, 0xc, 0x0, 0x0, 0x0, 0x0]),
+        ('-', 11, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe, 0x1fe, 0x1fe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('.', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('/', 9, [0x0, 0x0, 0x0, 0x0, 0x0, 0x180, 0x1c0, 0x1c0, 0xc0, 0xe0, 0xe0, 0x60, 0x70, 0x70, 0x30, 0x38, 0x38, 0x38, 0x1c, 0x1c, 0x1c, 0xc, 0xe, 0xe, 0x6, 0x7, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('0', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0xfc0, 0x3ff0, 0x7ff8, 0x7cf8, 0xf87c, 0xf87c, 0xf03c, 0x1f03e, 0x1f03e, 0x1f03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7cf8, 0x7ff8, 0x3ff0, 0xfc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('1', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0xfc0, 0xff8, 0xff8, 0xff8, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xfff8, 0xfff8, 0xfff8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('2', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0xff0, 0x3ffc, 0x7ffc, 0x7e1c, 0x7c04, 0xf800, 0x7800, 0x7c00, 0x7c00, 0x3e00, 0x1f00, 0xfc0, 0x7e0, 0x3f0, 0xf8, 0x7ffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('3', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0xff8, 0x3ff8, 0x7ff8, 0x7e18, 0x7c00, 0x7800, 0x7c00, 0x3e00, 0x1fe0, 0x3fe0, 0x7fe0, 0x7c00, 0xf800, 0xf800, 0xf800, 0xfc0c, 0x7ffc, 0x3ffc, 0xffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('4', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x3f00, 0x3f00, 0x3f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x3ef0, 0x3e70, 0x3e78, 0x3e3c, 0x3e1c, 0x3e1e, 0x1fffe, 0x1fffe, 0x1fffe, 0x7e00, 0x3e00, 0x3e00, 0x3c00, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('5', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7ff8, 0x7ff8, 0x7ff8, 0x7ff8, 0x78, 0x78, 0x3f8, 0x1ff8, 0x7ff8, 0x7ff8, 0xfc00, 0xf800, 0xf800, 0xf800, 0xf804, 0xfc1c, 0x7ffc, 0x3ffc, 0xff0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('6', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f80, 0x7fe0, 0x7ff0, 0x41f8, 0x78, 0x7c, 0x3c, 0x3ffc, 0x7ffc, 0xfffc, 0xf87c, 0xf07c, 0x1f07c, 0xf07c, 0xf07c, 0xf8f8, 0x7ff8, 0x3ff0, 0x1fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('7', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x7c00, 0x7c00, 0x3e00, 0x3e00, 0x1e00, 0x1f00, 0xf00, 0xf80, 0x780, 0x7c0, 0x7c0, 0x3e0, 0x3e0, 0x1f0, 0x1f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('8', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ff8, 0xf87c, 0xf87c, 0xf87c, 0xf87c, 0x7ff8, 0x1ff0, 0x3ff0, 0x7ff8, 0xf87c, 0xf03c, 0xf03c, 0xf03c, 0xf87c, 0xfffc, 0x7ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('9', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0xfe0, 0x1ff0, 0x3ff8, 0x7c7c, 0x783c, 0xf83e, 0xf83e, 0xf83c, 0xfc7c, 0xfffc, 0xfff8, 0xfff0, 0xf880, 0xf800, 0x7800, 0x7e08, 0x3ff8, 0x1ff8, 0x7f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        (':', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        (';', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x3c, 0x1c, 0xc, 0x0, 0x0, 0x0, 0x0]),
+        ('<', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x70000, 0x7e000, 0x7f800, 0xff00, 0x3fe0, 0x7f8, 0xf8, 0x1f8, 0xff8, 0x3fc0, 0x1fe00, 0x7f000, 0x7c000, 0x60000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('=', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7fff8, 0x7fff8, 0x0, 0x0, 0x0, 0x7fff8, 0x7fff8, 0x7fff8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('>', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x18, 0xf8, 0x7f8, 0x3fe0, 0xff00, 0x7f800, 0x7c000, 0x7e000, 0x3fc00, 0xff80, 0x1ff0, 0x3f8, 0x78, 0x18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('?', 15, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f8, 0xffc, 0x1ffc, 0x1f0c, 0x1e00, 0x1e00, 0x1f00, 0xf80, 0xfc0, 0x7e0, 0x3e0, 0x1f0, 0x1e0, 0x0, 0x1e0, 0x1f0, 0x1f0, 0x1f0, 0x1f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('@', 26, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe00, 0x7ff80, 0x1f07c0, 0x3c00e0, 0x380070, 0x700038, 0x637c18, 0xe3fe1c, 0xe3c70c, 0xc3870c, 0xe3870c, 0xe3870c, 0x63870c, 0x73870c, 0x3ffe1c, 0x1ffe18, 0x73838, 0x70, 0x400f0, 0xf03e0, 0x7ff80, 0x1fe00, 0x0, 0x0, 0x0, 0x0]),
+        ('A', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('B', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1ffc, 0x7ffc, 0xfffc, 0x1fcfc, 0x1f07c, 0x1f07c, 0x1f07c, 0xfffc, 0x7ffc, 0xfffc, 0x1fffc, 0x1f07c, 0x3e07c, 0x3e07c, 0x3e07c, 0x1f8fc, 0x1fffc, 0xfffc, 0x3ffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('C', 19, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x1fff0, 0x1e7f8, 0x100fc, 0x7c, 0x7c, 0x7c, 0x3e, 0x3e, 0x3e, 0x7e, 0x7c, 0x7c, 0x100fc, 0x1c3f8, 0x1fff0, 0x1ffe0, 0xff80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('D', 21, [0x0, 0x0, 0x0, 0x0, 0x0, 0xffc, 0xfffc, 0x1fffc, 0x3fffc, 0x7f07c, 0x7c07c, 0xfc07c, 0xf807c, 0xf807c, 0xf807c, 0xf807c, 0xf807c, 0xfc07c, 0x7c07c, 0x7f07c, 0x3fffc, 0x1fffc, 0xfffc, 0x1ffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('E', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('F', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('G', 21, [0x0, 0x0, 0x0, 0x0, 0x0, 0xff80, 0x7ffe0, 0x7fff0, 0x7c7f8, 0x601fc, 0xfc, 0x7c, 0x7c, 0x7f03e, 0x7f03e, 0x7f03e, 0x7f07e, 0x7807c, 0x7807c, 0x780fc, 0x7c3f8, 0x7fff0, 0x7ffe0, 0xff80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('H', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7fffc, 0x7fffc, 0x7fffc, 0x7fffc, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('I', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('J', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7f, 0x3f, 0x1f, 0x7, 0x0, 0x0, 0x0]),
+        ('K', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7c07c, 0x3e07c, 0x1f07c, 0xf87c, 0x7c7c, 0x3e7c, 0x1ffc, 0xffc, 0x7fc, 0x7fc, 0xffc, 0x1ffc, 0x3f7c, 0x7e7c, 0xfc7c, 0x1f87c, 0x3f07c, 0x7e07c, 0xfc07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('L', 16, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('M', 26, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7e01fc, 0x7e01fc, 0x7f03fc, 0x7f03fc, 0x7f83fc, 0x7f87fc, 0x7bc77c, 0x7bcf7c, 0x79cf7c, 0x79fe7c, 0x78fe7c, 0x78fc7c, 0x78fc7c, 0x78787c, 0x78787c, 0x78007c, 0x78007c, 0x78007c, 0x78007c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('N', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x780fc, 0x781fc, 0x781fc, 0x783fc, 0x783fc, 0x787fc, 0x787fc, 0x78f7c, 0x78f7c, 0x79e7c, 0x79e7c, 0x7bc7c, 0x7fc7c, 0x7f87c, 0x7f87c, 0x7f07c, 0x7e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('O', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('P', 19, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1ffc, 0x7ffc, 0x1fffc, 0x1fffc, 0x3f07c, 0x3e07c, 0x3e07c, 0x3e07c, 0x1f07c, 0x1fffc, 0xfffc, 0x7ffc, 0xfc, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Q', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0x7c0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0xff80, 0xf000, 0x1e000, 0x3e000, 0x7c000, 0x0, 0x0, 0x0, 0x0]),
+        ('R', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1ffc, 0x7ffc, 0xfffc, 0x1fffc, 0x1f07c, 0x1f07c, 0x1f07c, 0xf07c, 0xfffc, 0x7ffc, 0x3ffc, 0x7ffc, 0xf87c, 0x1f87c, 0x1f07c, 0x3f07c, 0x3e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('S', 19, [0x0, 0x0, 0x0, 0x0, 0x0, 0x3fe0, 0x7ff0, 0x7ff8, 0x78fc, 0x3c, 0x3c, 0x7c, 0x3fc, 0x3ff8, 0x7ff0, 0xffe0, 0xfe00, 0x1f800, 0x1f000, 0x1f00c, 0xfc7c, 0xfffc, 0x7ffc, 0x3ff0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('T', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1ffff, 0x1ffff, 0x1ffff, 0x1ffff, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('U', 21, [0x0, 0x0, 0x0, 0x0, 0x0, 0x3c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('V', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0xf801f, 0x7c01e, 0x7c03e, 0x7c03e, 0x3e07c, 0x3e07c, 0x1e07c, 0x1f0f8, 0x1f0f8, 0xf0f0, 0xf9f0, 0xf9f0, 0x79e0, 0x7fe0, 0x3fe0, 0x3fc0, 0x3fc0, 0x1f80, 0x1f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('W', 28, [0x0, 0x0, 0x0, 0x0, 0x0, 0x781f01e, 0x781f03e, 0x7c1f83e, 0x7c1f83c, 0x7c3f83c, 0x3c3f87c, 0x3c3bc7c, 0x3e3bc78, 0x3e79c78, 0x1e79cf8, 0x1e71ef8, 0x1f71ef8, 0x1f70ef0, 0xff0ff0, 0xfe0ff0, 0xfe0ff0, 0xfe07e0, 0xfe07e0, 0x7c07e0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('X', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7c03e, 0x3e07c, 0x1f07c, 0x1f0f8, 0xf9f0, 0x7df0, 0x7fe0, 0x3fc0, 0x1fc0, 0x1f80, 0x3fc0, 0x3fc0, 0x7fe0, 0xf9f0, 0xf9f8, 0x1f0f8, 0x3e07c, 0x3e07e, 0x7c03e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Y', 19, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7e01f, 0x3e03e, 0x1f07e, 0x1f87c, 0xf8f8, 0x7cf8, 0x7ff0, 0x3fe0, 0x1fe0, 0x1fc0, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Z', 19, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1fffc, 0x1fffe, 0x1fffe, 0x1fffc, 0xf800, 0x7c00, 0x3e00, 0x3f00, 0x1f80, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0x1f8, 0xfc, 0x1fffc, 0x3fffe, 0x3fffe, 0x1fffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('[', 12, [0x0, 0x0, 0x0, 0x0, 0x3fc, 0x3fc, 0x3fc, 0x7c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3c, 0x3fc, 0x3fc, 0x3fc, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('\\', 9, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7, 0x7, 0xe, 0xe, 0xe, 0xc, 0x1c, 0x1c, 0x18, 0x38, 0x38, 0x30, 0x70, 0x70, 0x70, 0xe0, 0xe0, 0xe0, 0x1c0, 0x1c0, 0x1c0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        (']', 12, [0x0, 0x0, 0x0, 0x0, 0x1fc, 0x3fc, 0x3fc, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3e0, 0x3fc, 0x3fc, 0x3fc, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('^', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1e00, 0x3f00, 0x7f80, 0xffc0, 0xf1e0, 0x1c0f0, 0x38078, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('_', 13, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fff, 0x1fff, 0x0, 0x0]),
+        ('`', 13, [0x0, 0x0, 0x0, 0x1c, 0x3c, 0x38, 0x70, 0xe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('a', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('b', 18, [0x0, 0x0, 0x0, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x3f7c, 0x7ffc, 0xfffc, 0x1f8fc, 0x1f07c, 0x1f07c, 0x1e07c, 0x1e07c, 0x1f07c, 0x1f07c, 0x1f8fc, 0xfffc, 0x7ffc, 0x3f7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('c', 15, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x3ffc, 0x7c, 0x3e, 0x3e, 0x3e, 0x3e, 0x3e, 0x7c, 0x30fc, 0x3ff8, 0x3ff0, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('d', 18, [0x0, 0x0, 0x0, 0x0, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf3f0, 0xfff8, 0xfffc, 0xf87c, 0xf83e, 0xf03e, 0xf03e, 0xf03e, 0xf03e, 0xf83c, 0xfc7c, 0xfffc, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('e', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('f', 11, [0x0, 0x0, 0x0, 0x0, 0x780, 0x7f0, 0x7f8, 0xf8, 0x78, 0x78, 0x7ff, 0x7ff, 0x7ff, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('g', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf3f0, 0xfff8, 0xfffc, 0xf87c, 0xf83e, 0xf03e, 0xf03e, 0xf03e, 0xf83e, 0xf87c, 0xfcfc, 0xfff8, 0xf7f0, 0xf1e0, 0xf800, 0xf808, 0x7ff8, 0x3ff8, 0x1ff8, 0x0, 0x0, 0x0]),
+        ('h', 18, [0x0, 0x0, 0x0, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x3f7c, 0x7ffc, 0xfffc, 0xf8fc, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('i', 9, [0x0, 0x0, 0x0, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('j', 9, [0x0, 0x0, 0x0, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x3c, 0x3f, 0x3f, 0x1f, 0x0, 0x0, 0x0]),
+        ('k', 17, [0x0, 0x0, 0x0, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0xf87c, 0x7c7c, 0x3e7c, 0x1f7c, 0xffc, 0x7fc, 0x3fc, 0x7fc, 0xffc, 0x1f7c, 0x3e7c, 0x7c7c, 0xf87c, 0x1f07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('l', 9, [0x0, 0x0, 0x0, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('m', 27, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7e3f7c, 0xff7ffc, 0xfffffc, 0x1f1f8fc, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x1f0f87c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('n', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3f7c, 0x7ffc, 0xfffc, 0xf8fc, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('o', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('p', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3f7c, 0x7ffc, 0xfffc, 0x1f8fc, 0x1f07c, 0x1f07c, 0x1e07c, 0x1e07c, 0x1f07c, 0x1f07c, 0x1f8fc, 0xfffc, 0x7ffc, 0x3f7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0]),
+        ('q', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf3f0, 0xfff8, 0xfffc, 0xf87c, 0xf83e, 0xf03e, 0xf03e, 0xf03e, 0xf03e, 0xf83c, 0xfc7c, 0xfffc, 0xf7f8, 0xf3f0, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x0, 0x0, 0x0]),
+        ('r', 13, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1f7c, 0x1ffc, 0x1ffc, 0x1fc, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('s', 15, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1ff8, 0x1ffc, 0x1ffc, 0x1e, 0x3e, 0x3fc, 0x1ffc, 0x3ff0, 0x3f00, 0x3c00, 0x3c04, 0x3ffc, 0x1ffc, 0xffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('t', 12, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x7c, 0xfff, 0xfff, 0xfff, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0xf8, 0x7f8, 0x7f0, 0x7e0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('u', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('v', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf01e, 0xf81e, 0x783e, 0x783c, 0x7c7c, 0x3c78, 0x3c78, 0x1ef8, 0x1ef0, 0x1ff0, 0xfe0, 0xfe0, 0x7e0, 0x7c0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('w', 24, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7c3c1e, 0x3c3c3e, 0x3c3e3c, 0x3c7e3c, 0x3e7e3c, 0x1e7e7c, 0x1e7778, 0x1ee778, 0x1fe778, 0xfe7f8, 0xfe3f0, 0xfc3f0, 0xfc3f0, 0x7c3e0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('x', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x783e, 0x7c7c, 0x3e78, 0x1ef8, 0x1ff0, 0xfe0, 0x7c0, 0x7e0, 0xff0, 0x1ff0, 0x3ef8, 0x3c7c, 0x7c3e, 0xf81e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('y', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf01e, 0xf81e, 0x783e, 0x783c, 0x7c7c, 0x3c78, 0x3c78, 0x1ef0, 0x1ef0, 0x1ff0, 0xfe0, 0xfe0, 0x7c0, 0x7c0, 0x780, 0x3c0, 0x3f0, 0x1f8, 0xf8, 0x0, 0x0, 0x0]),
+        ('z', 15, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3ffe, 0x3ffe, 0x3ffe, 0x1f00, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0xf8, 0x7c, 0x7e, 0x3ffe, 0x3ffe, 0x3ffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('{', 18, [0x0, 0x0, 0x0, 0x0, 0x7800, 0x7f00, 0x7f00, 0xf80, 0xf80, 0x780, 0x780, 0x780, 0x780, 0x780, 0x7c0, 0x3f8, 0x3f8, 0x7f8, 0x7c0, 0x780, 0x780, 0x780, 0x780, 0xf80, 0xf80, 0x7f80, 0x7f00, 0x7e00, 0x0, 0x0, 0x0, 0x0]),
+        ('|', 9, [0x0, 0x0, 0x0, 0x0, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x38, 0x0, 0x0]),
+        ('}', 18, [0x0, 0x0, 0x0, 0x0, 0x78, 0x3f8, 0x7f8, 0x7c0, 0x780, 0x780, 0x780, 0x780, 0x780, 0xf80, 0xf80, 0x7f00, 0x7e00, 0x7f00, 0xf80, 0x780, 0x780, 0x780, 0x780, 0x780, 0x7c0, 0x7f8, 0x3f8, 0x1f8, 0x0, 0x0, 0x0, 0x0]),
+        ('~', 22, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x607f0, 0x7fff8, 0x7ff78, 0x1f808, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('á', 17, [0x0, 0x0, 0x0, 0x1c00, 0x1c00, 0xe00, 0x700, 0x380, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ä', 17, [0x0, 0x0, 0x0, 0x0, 0x1ce0, 0x1ce0, 0x1ce0, 0x0, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('č', 15, [0x0, 0x0, 0x0, 0x1060, 0x18e0, 0xdc0, 0xf80, 0x780, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x3ffc, 0x7c, 0x3e, 0x3e, 0x3e, 0x3e, 0x3e, 0x7c, 0x30fc, 0x3ff8, 0x3ff0, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ď', 18, [0x0, 0x0, 0x0, 0x0, 0x38f000, 0x38f000, 0x3cf000, 0x1cf000, 0x1cf000, 0xf000, 0xf3f0, 0xfff8, 0xfffc, 0xf87c, 0xf83e, 0xf03e, 0xf03e, 0xf03e, 0xf03e, 0xf83c, 0xfc7c, 0xfffc, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('é', 17, [0x0, 0x0, 0x0, 0x3800, 0x1c00, 0xe00, 0x700, 0x300, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ě', 17, [0x0, 0x0, 0x0, 0x1060, 0x18e0, 0xdc0, 0xf80, 0x780, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('í', 9, [0x0, 0x0, 0x0, 0x1c0, 0x1c0, 0xe0, 0x70, 0x38, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ĺ', 9, [0x1e0, 0xe0, 0x70, 0x0, 0x3c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ľ', 12, [0x0, 0x0, 0x0, 0x0, 0xe3c, 0xe7c, 0x77c, 0x77c, 0x77c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ň', 18, [0x0, 0x0, 0x0, 0x1830, 0x1c60, 0xee0, 0x7c0, 0x380, 0x0, 0x0, 0x3f7c, 0x7ffc, 0xfffc, 0xf8fc, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ó', 18, [0x0, 0x0, 0x0, 0x3800, 0x1c00, 0xe00, 0x700, 0x300, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ô', 18, [0x0, 0x0, 0x0, 0x380, 0x780, 0xfc0, 0x1ce0, 0x1870, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ŕ', 13, [0x0, 0x0, 0x0, 0x1c00, 0xe00, 0x700, 0x380, 0x180, 0x0, 0x0, 0x1f7c, 0x1ffc, 0x1ffc, 0x1fc, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ř', 13, [0x0, 0x0, 0x0, 0xc18, 0x630, 0x770, 0x3e0, 0x1c0, 0x0, 0x0, 0x1f7c, 0x1ffc, 0x1ffc, 0x1fc, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('š', 15, [0x0, 0x0, 0x0, 0xc18, 0xe30, 0x770, 0x3e0, 0x1c0, 0x0, 0x0, 0x1ff8, 0x1ffc, 0x1ffc, 0x1e, 0x3e, 0x3fc, 0x1ffc, 0x3ff0, 0x3f00, 0x3c00, 0x3c04, 0x3ffc, 0x1ffc, 0xffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ť', 12, [0x0, 0x0, 0x0, 0x1e00, 0xe00, 0xe00, 0x678, 0x678, 0x78, 0x7c, 0xfff, 0xfff, 0xfff, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0xf8, 0x7f8, 0x7f0, 0x7e0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ú', 18, [0x0, 0x0, 0x0, 0x3800, 0x3c00, 0x1c00, 0xe00, 0x700, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ů', 18, [0x0, 0x700, 0xf80, 0x1cc0, 0x18c0, 0x18c0, 0xfc0, 0x780, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ý', 17, [0x0, 0x0, 0x0, 0x1c00, 0xe00, 0xf00, 0x700, 0x380, 0x0, 0x0, 0xf01e, 0xf81e, 0x783e, 0x783c, 0x7c7c, 0x3c78, 0x3c78, 0x1ef0, 0x1ef0, 0x1ff0, 0xfe0, 0xfe0, 0x7c0, 0x7c0, 0x780, 0x3c0, 0x3f0, 0x1f8, 0xf8, 0x0, 0x0, 0x0]),
+        ('ž', 15, [0x0, 0x0, 0x0, 0xc18, 0x630, 0x770, 0x3e0, 0x1c0, 0x0, 0x0, 0x3ffe, 0x3ffe, 0x3ffe, 0x1f00, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0xf8, 0x7c, 0x7e, 0x3ffe, 0x3ffe, 0x3ffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Á', 20, [0x1c00, 0xe00, 0x700, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ä', 20, [0x39c0, 0x39c0, 0x39c0, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Č', 19, [0x6180, 0x3380, 0x3f00, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x1fff0, 0x1e7f8, 0x100fc, 0x7c, 0x7c, 0x7c, 0x3e, 0x3e, 0x3e, 0x7e, 0x7c, 0x7c, 0x100fc, 0x1c3f8, 0x1fff0, 0x1ffe0, 0xff80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ď', 21, [0x30e0, 0x1dc0, 0xf80, 0x0, 0x0, 0xffc, 0xfffc, 0x1fffc, 0x3fffc, 0x7f07c, 0x7c07c, 0xfc07c, 0xf807c, 0xf807c, 0xf807c, 0xf807c, 0xf807c, 0xfc07c, 0x7c07c, 0x7f07c, 0x3fffc, 0x1fffc, 0xfffc, 0x1ffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('É', 18, [0xe00, 0x700, 0x380, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ě', 18, [0x1860, 0xce0, 0xfc0, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Í', 10, [0xe0, 0x70, 0x38, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ĺ', 16, [0x1c0, 0xe0, 0x70, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ľ', 16, [0x0, 0x0, 0x0, 0x0, 0x0, 0x707c, 0x707c, 0x707c, 0x387c, 0x387c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ň', 22, [0x6180, 0x7300, 0x3f00, 0x0, 0x0, 0x780fc, 0x781fc, 0x781fc, 0x783fc, 0x783fc, 0x787fc, 0x787fc, 0x78f7c, 0x78f7c, 0x79e7c, 0x79e7c, 0x7bc7c, 0x7fc7c, 0x7f87c, 0x7f87c, 0x7f07c, 0x7e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ó', 22, [0x3800, 0x1c00, 0xe00, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ô', 22, [0x1f00, 0x3f80, 0x7180, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ŕ', 20, [0xe00, 0x700, 0x300, 0x0, 0x0, 0x1ffc, 0x7ffc, 0xfffc, 0x1fffc, 0x1f07c, 0x1f07c, 0x1f07c, 0xf07c, 0xfffc, 0x7ffc, 0x3ffc, 0x7ffc, 0xf87c, 0x1f87c, 0x1f07c, 0x3f07c, 0x3e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ř', 20, [0x1860, 0xce0, 0xfc0, 0x0, 0x0, 0x1ffc, 0x7ffc, 0xfffc, 0x1fffc, 0x1f07c, 0x1f07c, 0x1f07c, 0xf07c, 0xfffc, 0x7ffc, 0x3ffc, 0x7ffc, 0xf87c, 0x1f87c, 0x1f07c, 0x3f07c, 0x3e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Š', 19, [0x1860, 0xce0, 0xfc0, 0x0, 0x0, 0x3fe0, 0x7ff0, 0x7ff8, 0x78fc, 0x3c, 0x3c, 0x7c, 0x3fc, 0x3ff8, 0x7ff0, 0xffe0, 0xfe00, 0x1f800, 0x1f000, 0x1f00c, 0xfc7c, 0xfffc, 0x7ffc, 0x3ff0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ť', 18, [0x1870, 0xee0, 0x7c0, 0x0, 0x0, 0x1ffff, 0x1ffff, 0x1ffff, 0x1ffff, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ú', 21, [0x3c00, 0x1c00, 0xe00, 0x0, 0x0, 0x3c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ů', 21, [0xe00, 0x1f00, 0x3180, 0x3180, 0x3180, 0x3fffc, 0x7ce7c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ý', 19, [0x1e00, 0xe00, 0x700, 0x0, 0x0, 0x7e01f, 0x3e03e, 0x1f07e, 0x1f87c, 0xf8f8, 0x7cf8, 0x7ff0, 0x3fe0, 0x1fe0, 0x1fc0, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0xf80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ž', 19, [0x1860, 0x1cc0, 0xfc0, 0x0, 0x0, 0x1fffc, 0x1fffe, 0x1fffe, 0x1fffc, 0xf800, 0x7c00, 0x3e00, 0x3f00, 0x1f80, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0x1f8, 0xfc, 0x1fffc, 0x3fffe, 0x3fffe, 0x1fffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ß', 19, [0x0, 0x0, 0x0, 0x0, 0x300, 0x1ff0, 0x7ff8, 0x7cf8, 0x787c, 0xf87c, 0xfc7c, 0x1e7c, 0xf7c, 0xf7c, 0x1f7c, 0x7e7c, 0xfe7c, 0x1f87c, 0x1f07c, 0x1e07c, 0x1e07c, 0x1ff7c, 0xff7c, 0x7f7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ö', 18, [0x0, 0x0, 0x0, 0x0, 0x1ce0, 0x1ce0, 0x1ce0, 0x0, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ü', 18, [0x0, 0x0, 0x0, 0x0, 0x1ce0, 0x1ce0, 0x1ce0, 0x0, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ö', 22, [0x7380, 0x7380, 0x7380, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ü', 21, [0x3980, 0x3980, 0x3980, 0x0, 0x0, 0x3c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ñ', 18, [0x0, 0x0, 0x0, 0x0, 0x31c0, 0x3fe0, 0x1e60, 0x0, 0x0, 0x0, 0x3f7c, 0x7ffc, 0xfffc, 0xf8fc, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ñ', 22, [0x6380, 0x3fc0, 0x3cc0, 0x0, 0x0, 0x780fc, 0x781fc, 0x781fc, 0x783fc, 0x783fc, 0x787fc, 0x787fc, 0x78f7c, 0x78f7c, 0x79e7c, 0x79e7c, 0x7bc7c, 0x7fc7c, 0x7f87c, 0x7f87c, 0x7f07c, 0x7e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('¿', 15, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7c0, 0x7c0, 0x7c0, 0x7c0, 0x0, 0x0, 0x7c0, 0x7c0, 0x3e0, 0x3f0, 0x1f8, 0xf8, 0x7c, 0x3c, 0x107c, 0x1cfc, 0x1ffc, 0x1ff8, 0x7e0, 0x0, 0x0, 0x0]),
+        ('¡', 12, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf0, 0xf0, 0xf0, 0xf0, 0x0, 0x0, 0x70, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0x0, 0x0, 0x0]),
+        ('à', 17, [0x0, 0x0, 0x0, 0x70, 0xe0, 0x1c0, 0x180, 0x300, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('è', 17, [0x0, 0x0, 0x0, 0xf0, 0xe0, 0x1c0, 0x380, 0x700, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ì', 9, [0x0, 0x0, 0x0, 0x7, 0xe, 0x1c, 0x18, 0x30, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ò', 18, [0x0, 0x0, 0x0, 0x70, 0xe0, 0x1c0, 0x380, 0x700, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ù', 18, [0x0, 0x0, 0x0, 0xe0, 0x1e0, 0x1c0, 0x380, 0x700, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('â', 17, [0x0, 0x0, 0x0, 0x380, 0x7c0, 0xfc0, 0xce0, 0x1870, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ê', 17, [0x0, 0x0, 0x0, 0x700, 0xf80, 0xfc0, 0x1ce0, 0x3860, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('î', 9, [0x0, 0x0, 0x0, 0x38, 0x7c, 0x7e, 0xc6, 0x1c3, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('û', 18, [0x0, 0x0, 0x0, 0x700, 0x780, 0xfc0, 0x1ce0, 0x1860, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ç', 15, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x3ffc, 0x7c, 0x3e, 0x3e, 0x3e, 0x3e, 0x3e, 0x7c, 0x30fc, 0x3ff8, 0x3ff0, 0x1fe0, 0x300, 0x600, 0x600, 0x7c0, 0x3c0, 0x0, 0x0, 0x0]),
+        ('À', 20, [0x1c0, 0x380, 0x700, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('È', 18, [0xe0, 0x1c0, 0x380, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ì', 10, [0xe, 0x1c, 0x38, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ò', 22, [0x380, 0x700, 0xe00, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ù', 21, [0x3c0, 0x700, 0x600, 0x0, 0x0, 0x3c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Â', 20, [0xf00, 0x1f80, 0x39c0, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ê', 18, [0x7c0, 0xfc0, 0x1c60, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Î', 10, [0x7c, 0xfc, 0x1c6, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Û', 21, [0x1f00, 0x3f80, 0x31c0, 0x0, 0x0, 0x3c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ç', 19, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x1fff0, 0x1e7f8, 0x100fc, 0x7c, 0x7c, 0x7c, 0x3e, 0x3e, 0x3e, 0x7e, 0x7c, 0x7c, 0x100fc, 0x1c3f8, 0x1fff0, 0x1ffe0, 0xff80, 0x1800, 0x1800, 0x3800, 0x1f00, 0x1f00, 0x0, 0x0, 0x0]),
+        ('ë', 17, [0x0, 0x0, 0x0, 0x0, 0x1ce0, 0x1ce0, 0x1ce0, 0x0, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ï', 9, [0x0, 0x0, 0x0, 0x0, 0xe7, 0xe7, 0xe7, 0x0, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ÿ', 17, [0x0, 0x0, 0x0, 0x0, 0xe70, 0xe70, 0xe70, 0x0, 0x0, 0x0, 0xf01e, 0xf81e, 0x783e, 0x783c, 0x7c7c, 0x3c78, 0x3c78, 0x1ef0, 0x1ef0, 0x1ff0, 0xfe0, 0xfe0, 0x7c0, 0x7c0, 0x780, 0x3c0, 0x3f0, 0x1f8, 0xf8, 0x0, 0x0, 0x0]),
+        ('Ë', 18, [0x1ce0, 0x1ce0, 0x1ce0, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ï', 10, [0x1ce, 0x1ce, 0x1ce, 0x0, 0x0, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x7c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ã', 17, [0x0, 0x0, 0x0, 0x0, 0x19e0, 0x1fe0, 0xf20, 0x0, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('õ', 18, [0x0, 0x0, 0x0, 0x0, 0x19e0, 0x1fe0, 0x1f20, 0x0, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ã', 20, [0x3380, 0x3fc0, 0x3cc0, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Õ', 22, [0x6700, 0x7f80, 0x7980, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ą', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1ffc, 0x3ffc, 0x7ffc, 0x7c00, 0x7800, 0x7ff0, 0x7ffc, 0x7ffc, 0x783e, 0x7c3e, 0x7c3e, 0x7ffe, 0x7bfc, 0x79f8, 0xc00, 0xc00, 0xc00, 0x7c00, 0x7800, 0x0, 0x0, 0x0]),
+        ('ę', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1fe0, 0x3ff8, 0x7ffc, 0xf87c, 0xf83e, 0xfffe, 0xfffe, 0xfffe, 0x3e, 0x3c, 0xe07c, 0xfff8, 0xfff8, 0x3fe0, 0xc00, 0xc00, 0xc00, 0x7c00, 0x7c00, 0x0, 0x0, 0x0]),
+        ('ł', 10, [0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x1f8, 0x3f8, 0x1f8, 0x7c, 0x7f, 0x7f, 0x7e, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ń', 18, [0x0, 0x0, 0x0, 0x1c00, 0xe00, 0x700, 0x380, 0x180, 0x0, 0x0, 0x3f7c, 0x7ffc, 0xfffc, 0xf8fc, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ś', 15, [0x0, 0x0, 0x0, 0x1c00, 0xe00, 0x700, 0x380, 0x180, 0x0, 0x0, 0x1ff8, 0x1ffc, 0x1ffc, 0x1e, 0x3e, 0x3fc, 0x1ffc, 0x3ff0, 0x3f00, 0x3c00, 0x3c04, 0x3ffc, 0x1ffc, 0xffc, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ź', 15, [0x0, 0x0, 0x0, 0x1c00, 0xe00, 0x700, 0x380, 0x180, 0x0, 0x0, 0x3ffe, 0x3ffe, 0x3ffe, 0x1f00, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0xf8, 0x7c, 0x7e, 0x3ffe, 0x3ffe, 0x3ffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ż', 15, [0x0, 0x0, 0x0, 0x0, 0x1c0, 0x3e0, 0x3e0, 0x3e0, 0x0, 0x0, 0x3ffe, 0x3ffe, 0x3ffe, 0x1f00, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0xf8, 0x7c, 0x7e, 0x3ffe, 0x3ffe, 0x3ffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ą', 20, [0x0, 0x0, 0x0, 0x0, 0x0, 0x1f80, 0x1f80, 0x3fc0, 0x3fc0, 0x3fe0, 0x7fe0, 0x79e0, 0xf9f0, 0xf9f0, 0xf0f0, 0x1f0f8, 0x1f0f8, 0x1fff8, 0x3fffc, 0x3fffc, 0x7e07e, 0x7c03e, 0x7c01e, 0xf801f, 0x8000, 0xc000, 0xc000, 0x7c000, 0x78000, 0x0, 0x0, 0x0]),
+        ('Ę', 18, [0x0, 0x0, 0x0, 0x0, 0x0, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7ffc, 0x7ffc, 0x7ffc, 0x7ffc, 0x7c, 0x7c, 0x7c, 0x7c, 0xfffc, 0xfffc, 0xfffc, 0xfffc, 0x1800, 0x1800, 0x1c00, 0xf800, 0xf800, 0x0, 0x0, 0x0]),
+        ('Ł', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x478, 0xe78, 0xff8, 0x7f8, 0x1f8, 0xfc, 0x7e, 0x7f, 0x7f, 0x7f, 0x78, 0x78, 0xfff8, 0xfff8, 0xfff8, 0xfff8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ń', 22, [0x3c00, 0x1c00, 0xe00, 0x0, 0x0, 0x780fc, 0x781fc, 0x781fc, 0x783fc, 0x783fc, 0x787fc, 0x787fc, 0x78f7c, 0x78f7c, 0x79e7c, 0x79e7c, 0x7bc7c, 0x7fc7c, 0x7f87c, 0x7f87c, 0x7f07c, 0x7e07c, 0x7e07c, 0x7c07c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ś', 19, [0xe00, 0x700, 0x300, 0x0, 0x0, 0x3fe0, 0x7ff0, 0x7ff8, 0x78fc, 0x3c, 0x3c, 0x7c, 0x3fc, 0x3ff8, 0x7ff0, 0xffe0, 0xfe00, 0x1f800, 0x1f000, 0x1f00c, 0xfc7c, 0xfffc, 0x7ffc, 0x3ff0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ź', 19, [0xe00, 0x700, 0x300, 0x0, 0x0, 0x1fffc, 0x1fffe, 0x1fffe, 0x1fffc, 0xf800, 0x7c00, 0x3e00, 0x3f00, 0x1f80, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0x1f8, 0xfc, 0x1fffc, 0x3fffe, 0x3fffe, 0x1fffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ż', 19, [0x780, 0x780, 0x780, 0x0, 0x0, 0x1fffc, 0x1fffe, 0x1fffe, 0x1fffc, 0xf800, 0x7c00, 0x3e00, 0x3f00, 0x1f80, 0xf80, 0x7c0, 0x3e0, 0x1f0, 0x1f8, 0xfc, 0x1fffc, 0x3fffe, 0x3fffe, 0x1fffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ő', 18, [0x0, 0x0, 0x0, 0x7300, 0x3b80, 0x19c0, 0x1cc0, 0xce0, 0x0, 0x0, 0x1fe0, 0x7ff8, 0x7ffc, 0xf87c, 0xf83e, 0xf03e, 0x1f03e, 0x1f03e, 0xf03e, 0xf83c, 0xf87c, 0x7ff8, 0x3ff8, 0x1fe0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('ű', 18, [0x0, 0x0, 0x0, 0x6300, 0x7380, 0x3980, 0x19c0, 0xcc0, 0x0, 0x0, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf07c, 0xf87c, 0xf87c, 0xfcfc, 0xfff8, 0xf7f8, 0xf3f0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ő', 22, [0xef00, 0x7700, 0x3380, 0x0, 0x0, 0x7f80, 0x1ffe0, 0x3fff0, 0x7f3f8, 0xfc0fc, 0xf807c, 0xf807c, 0xf807e, 0x1f803e, 0x1f003e, 0x1f003e, 0xf807e, 0xf807c, 0xf807c, 0xfc0fc, 0x7f3f8, 0x3fff0, 0x1ffe0, 0x7f80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('Ű', 21, [0xe700, 0x7380, 0x39c0, 0x0, 0x0, 0x3c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x7c07c, 0x3c07c, 0x3e078, 0x3e0f8, 0x3f1f8, 0x1fff0, 0xffe0, 0x7fc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('–', 13, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x7fc, 0xffe, 0xffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('—', 26, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xfffffc, 0xfffffe, 0xfffffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('’', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x78, 0x3c, 0x1c, 0x1c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('“', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x30e0, 0x38e0, 0x3cf0, 0x3c78, 0x1e78, 0x1e78, 0x1e78, 0x1e78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('”', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x3cf0, 0x3cf0, 0x3cf0, 0x3cf0, 0x3c78, 0x1e78, 0xe38, 0xe18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('„', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3c78, 0x3e78, 0x3e78, 0x3e78, 0x1e78, 0x1e38, 0xe3c, 0x71c, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('«', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2080, 0x30c0, 0x3cf0, 0x1e78, 0xf3c, 0x71c, 0xf3c, 0x1e78, 0x38e0, 0x30c0, 0x2080, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('»', 17, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x30c, 0x71c, 0xe38, 0x3cf0, 0x79e0, 0x71c0, 0x78e0, 0x1e78, 0xf3c, 0x71c, 0x104, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('·', 10, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x78, 0x78, 0x78, 0x78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        ('…', 26, [0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0xf8787c, 0xf8787c, 0xf8787c, 0xf8787c, 0xf8787c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+    ];
+
+    dataset
+        .into_iter()
+        .map(|(c, advance, rows)| (c, Glyph { advance, rows }))
+        .collect()
+}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+
+use flate2::{Compress, Compression, Crc, FlushCompress};
+
+use crate::dictionary::{localization, Dictionary, MeaningType, Term};
+use crate::dictionary::localization::{heading, Heading};
+
+// maximal size of uncompressed chunk as used by dictzip
+const CHUNK_SIZE: usize = 58315;
+
+const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn to_dictd(dict: &Dictionary, output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
+    super::prepare_output_dir(output_path, force)?;
+
+    let name = database_name(dict);
+    let (data, index) = create_dictd_data(dict);
+
+    let mut f = fs::File::create(format!("{}/{}.index", output_path, name))?;
+    f.write_all(index.as_bytes())?;
+
+    let mut f = fs::File::create(format!("{}/{}.dict.dz", output_path, name))?;
+    f.write_all(&dictzip(data.as_bytes(), &format!("{}.dict", name))?)?;
+
+    Ok(())
+}
+
+pub fn database_name(dict: &Dictionary) -> String {
+    format!("{}-{}", dict.source_language, dict.target_language)
+}
+
+pub fn database_info(dict: &Dictionary) -> String {
+    let mut out_str = format!("{}\n\n", dict.title);
+    let language = dict.ui_language.as_str();
+    if !dict.author.is_empty() {
+        out_str.push_str(format!("{}: {}\n", heading(language, &Heading::Author), dict.author).as_str());
+    }
+    out_str.push_str(format!("{}: {}\n", heading(language, &Heading::SourceLanguage), dict.source_language).as_str());
+    out_str.push_str(format!("{}: {}\n", heading(language, &Heading::TargetLanguage), dict.target_language).as_str());
+    out_str.push_str(format!("{}: {}\n", heading(language, &Heading::Records), dict.non_empty_len()).as_str());
+    let sources = dict.used_sources();
+    if !sources.is_empty() {
+        out_str.push_str(format!("\n{}:\n", heading(language, &Heading::Licence)).as_str());
+    }
+    for source in sources {
+        let licence = format!("{} {}", source.licence_name(), source.licence_url());
+        out_str.push_str(format!("{}\n    {}\n", source.attribution(), licence.trim_end()).as_str());
+    }
+    out_str
+}
+
+// returns dictionary data and the index pointing into it
+fn create_dictd_data(dict: &Dictionary) -> (String, String) {
+    let entries = [
+        ("00-database-short".to_string(), format!("00-database-short\n    {}\n", dict.title)),
+        ("00-database-info".to_string(), format!("00-database-info\n{}", indent(&database_info(dict)))),
+        ("00-database-utf8".to_string(), "00-database-utf8\n".to_string()),
+    ];
+
+    let mut terms = dict
+        .all_terms()
+        .filter(|term| !term.is_empty())
+        .collect::<Vec<_>>();
+    terms.sort_by_key(|term| (sort_key(&term.headword), term.headword.clone()));
+
+    let mut data = String::new();
+    let mut index = Vec::new();
+    for (headword, body) in entries.iter() {
+        index.push((headword.clone(), data.len(), body.len()));
+        data.push_str(body);
+    }
+
+    let mut positions = HashMap::new();
+    for term in terms {
+        let body = format_term(term, &dict.ui_language);
+        positions.insert(&term.headword, (data.len(), body.len()));
+        index.push((term.headword.clone(), data.len(), body.len()));
+        // secondary key for diacritic-insensitive lookup
+        let key = dict.key(&term.headword);
+        if let Some(folded) = dict.normalization.folded_key(&key).filter(|folded| *folded != key) {
+            index.push((folded, data.len(), body.len()));
+        }
+        data.push_str(&body);
+    }
+
+    // inflected forms point to the entries of their headwords
+    for (form, headwords) in dict.inflection_index.iter() {
+        for headword in headwords {
+            if *form == dict.key(headword) {
+                continue;
+            }
+            if let Some((offset, len)) = positions.get(headword) {
+                index.push((form.clone(), *offset, *len));
+            }
+        }
+    }
+
+    index.sort_by(|a, b| (sort_key(&a.0), &a.0, a.1).cmp(&(sort_key(&b.0), &b.0, b.1)));
+    let index = index
+        .iter()
+        .map(|(headword, offset, len)| format!("{}\t{}\t{}\n", headword, encode_base64(*offset), encode_base64(*len)))
+        .collect::<String>();
+
+    (data, index)
+}
+
+// plain text rendering of a term used by dictd database and DICT protocol server
+pub fn format_term(term: &Term, language: &str) -> String {
+    let mut out_str = format!("{}\n", term.headword);
+
+    format_pronunciations(&mut out_str, term, language);
+    format_classes(&mut out_str, term, language);
+
+    out_str
+}
+
+fn format_pronunciations(out_str: &mut String, term: &Term, language: &str) {
+    let mut pron_keys = term.pronunciations.keys().collect::<Vec<_>>();
+    pron_keys.sort();
+    for name in pron_keys {
+        if term.pronunciations.len() > 1 && name == "wiki" {
+            // skip wiki pronunciation if we have record from other sources
+            continue;
+        }
+        let pronunciations = term.pronunciations.get(name).unwrap();
+        out_str.push_str("    ");
+        if !name.is_empty() && name != "wiki" {
+            out_str.push_str(format!("{}: ", localization::pronunciation_name(language, name)).as_str());
+        }
+        out_str.push_str(pronunciations.join(", ").as_str());
+        out_str.push('\n');
+    }
+}
+
+fn format_classes(out_str: &mut String, term: &Term, language: &str) {
+    let mut classes = term.classes.keys().collect::<Vec<_>>();
+    classes.sort();
+    for word_class in classes {
+        let meanings = term.classes.get(word_class).unwrap();
+        if meanings.is_empty() {
+            continue;
+        }
+
+        out_str.push_str(format!("\n    {}\n", localization::word_class(language, word_class)).as_str());
+        format_meanings(out_str, meanings);
+    }
+}
+
+fn format_meanings(out_str: &mut String, meanings: &MeaningType) {
+    let mut translations = meanings
+        .values()
+        .flat_map(|meaning| meaning.translations.keys().map(|t| t.as_str()))
+        .collect::<Vec<_>>();
+    translations.sort();
+    translations.dedup();
+    if !translations.is_empty() {
+        out_str.push_str(format!("        {}\n", translations.join(" | ")).as_str());
+    }
+
+    let mut values = meanings.values().filter(|m| !m.description.is_empty()).collect::<Vec<_>>();
+    values.sort_by_key(|a| a.order);
+    for (i, meaning) in values.iter().enumerate() {
+        out_str.push_str(format!("        {}. {}\n", i + 1, meaning.description).as_str());
+    }
+}
+
+fn indent(text: &str) -> String {
+    text
+        .lines()
+        .map(|line| if line.is_empty() { "\n".to_string() } else { format!("    {}\n", line) })
+        .collect()
+}
+
+// dictd compares headwords case insensitively and ignores everything but letters, digits and spaces
+pub fn sort_key(headword: &str) -> String {
+    headword
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect()
+}
+
+fn encode_base64(mut value: usize) -> String {
+    let mut out = vec![BASE64[value & 0x3f]];
+    value >>= 6;
+    while value > 0 {
+        out.push(BASE64[value & 0x3f]);
+        value >>= 6;
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap()
+}
+
+// gzip file with random access extra field as created by dictzip
+fn dictzip(data: &[u8], file_name: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut compressor = Compress::new(Compression::best(), false);
+    let mut chunks = Vec::new();
+    let count = data.len().div_ceil(CHUNK_SIZE).max(1);
+    for i in 0..count {
+        let chunk = &data[(i * CHUNK_SIZE).min(data.len())..((i + 1) * CHUNK_SIZE).min(data.len())];
+        let flush = if i + 1 == count { FlushCompress::Finish } else { FlushCompress::Full };
+        let mut out = Vec::with_capacity(chunk.len() + 1024);
+        let start = compressor.total_in();
+        loop {
+            let consumed = (compressor.total_in() - start) as usize;
+            compressor.compress_vec(&chunk[consumed..], &mut out, flush)?;
+            if (compressor.total_in() - start) as usize == chunk.len() && out.len() < out.capacity() {
+                break;
+            }
+            out.reserve(out.capacity());
+        }
+        chunks.push(out);
+    }
+
+    let xlen = 10 + 2 * chunks.len();
+    if xlen > u16::MAX as usize {
+        Err("Dictionary is too large for dictzip format.")?;
+    }
+
+    let mut out = vec![0x1f, 0x8b, 8, 0x04 | 0x08, 0, 0, 0, 0, 2, 3];
+    out.extend_from_slice(&(xlen as u16).to_le_bytes());
+    out.extend_from_slice(b"RA");
+    out.extend_from_slice(&((xlen - 4) as u16).to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&(CHUNK_SIZE as u16).to_le_bytes());
+    out.extend_from_slice(&(chunks.len() as u16).to_le_bytes());
+    for chunk in chunks.iter() {
+        out.extend_from_slice(&(chunk.len() as u16).to_le_bytes());
+    }
+    out.extend_from_slice(file_name.as_bytes());
+    out.push(0);
+
+    for chunk in chunks.iter() {
+        out.extend_from_slice(chunk);
+    }
+
+    let mut crc = Crc::new();
+    crc.update(data);
+    out.extend_from_slice(&crc.sum().to_le_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+
+    Ok(out)
+}
@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+
+use crate::cli_config::EntryLayout;
+use crate::dictionary::{Dictionary, Term};
+
+use super::{dictd::sort_key, escape_xml, kindle};
+
+// publication resources are placed into this directory of the package
+const CONTENT_DIR: &str = "OEBPS";
+const SEARCH_KEY_MAP: &str = "search-key-map.xml";
+const BATCH_SIZE: usize = 30_000;
+
+const CONTAINER: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+    <rootfiles>
+        <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml" />
+    </rootfiles>
+</container>
+"#;
+
+// page of the publication as (id, title)
+type Page = (String, String);
+
+// writes the unpacked package into the output directory and packs it into <source>-<target>.epub
+pub fn to_epub(dict: &Dictionary, output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
+    super::prepare_output_dir(output_path, force)?;
+    let content_path = format!("{}/{}", output_path, CONTENT_DIR);
+    fs::create_dir_all(format!("{}/META-INF", output_path))?;
+    fs::create_dir_all(&content_path)?;
+
+    fs::write(format!("{}/mimetype", output_path), "application/epub+zip")?;
+    fs::write(format!("{}/META-INF/container.xml", output_path), CONTAINER)?;
+
+    let cover = super::cover::create_cover(dict, &content_path)?;
+    let front_matter = create_epub_front_matter(dict, &content_path)?;
+    let files = create_epub_content_files(dict, &content_path)?;
+    create_epub_nav_file(dict, &content_path, &front_matter, &files)?;
+    create_epub_opf_file(dict, &content_path, &cover, &front_matter, &files)?;
+    super::validator::validate_epub(output_path)?;
+
+    let mut names = vec!["mimetype".to_string(), "META-INF/container.xml".to_string()];
+    let resources = ["content.opf", "nav.xhtml", SEARCH_KEY_MAP, cover.0.as_str()];
+    names.extend(resources.iter().map(|name| format!("{}/{}", CONTENT_DIR, name)));
+    let pages = front_matter.iter().chain(files.iter());
+    names.extend(pages.map(|(id, _)| format!("{}/{}.xhtml", CONTENT_DIR, id)));
+    let archive = format!("{}/{}.epub", output_path, super::dictd::database_name(dict));
+    fs::write(archive, create_zip(output_path, &names)?)?;
+
+    Ok(())
+}
+
+fn xhtml_page(title: &str, language: &str, body: &str) -> String {
+    format!(r#"<?xml version="1.0" encoding="utf-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="{language}" lang="{language}">
+<head>
+    <meta charset="utf-8" />
+    <title>{}</title>
+</head>
+<body>
+{}</body>
+</html>
+"#, escape_xml(title), body, language = escape_xml(language))
+}
+
+fn create_epub_front_matter(dict: &Dictionary, content_path: &str) -> Result<Vec<Page>, Box<dyn Error>> {
+    let mut front_matter = vec![];
+    for (id, _, title, body) in kindle::front_matter_pages(dict) {
+        fs::write(format!("{}/{}.xhtml", content_path, id), xhtml_page(title, &dict.ui_language, &body))?;
+        front_matter.push((id.to_string(), title.to_string()));
+    }
+    Ok(front_matter)
+}
+
+// entries are split into files of BATCH_SIZE entries, each entry has its group in the search key map
+fn create_epub_content_files(dict: &Dictionary, content_path: &str) -> Result<Vec<Page>, Box<dyn Error>> {
+    let mut terms = dict.all_terms().filter(|term| !term.is_empty()).collect::<Vec<_>>();
+    terms.sort_by_key(|term| (sort_key(&term.headword), term.headword.clone()));
+    let mut search_key_map = fs::File::create(format!("{}/{}", content_path, SEARCH_KEY_MAP))?;
+    search_key_map.write_all(format!(r#"<?xml version="1.0" encoding="utf-8"?>
+<search-key-map xmlns="http://www.idpf.org/2007/ops" xml:lang="{}">
+"#, escape_xml(&dict.source_language)).as_bytes())?;
+
+    let mut files = vec![];
+    for (i, batch) in terms.chunks(BATCH_SIZE).enumerate() {
+        let id = format!("content{:04}", i + 1);
+        let mut body = "<section epub:type=\"dictionary\">\n".to_string();
+        for (j, &term) in batch.iter().enumerate() {
+            let entry_id = format!("e{}", i * BATCH_SIZE + j + 1);
+            body.push_str(&format_entry(term, &entry_id, &dict.ui_language, &dict.entry_layout));
+            search_key_map.write_all(format_search_keys(term, &format!("{}.xhtml#{}", id, entry_id)).as_bytes())?;
+        }
+        body.push_str("</section>\n");
+        fs::write(format!("{}/{}.xhtml", content_path, id), xhtml_page(&dict.title, &dict.source_language, &body))?;
+        files.push((id, dict.title.clone()));
+    }
+
+    search_key_map.write_all("</search-key-map>\n".as_bytes())?;
+    Ok(files)
+}
+
+// the same presentation as kindle entry in EPUB dictionary markup
+fn format_entry(term: &Term, id: &str, language: &str, layout: &EntryLayout) -> String {
+    let mut out_str = format!(
+        "<article epub:type=\"dictentry\" id=\"{}\">\n<h3><dfn>{}</dfn></h3>\n",
+        id, escape_xml(&term.headword),
+    );
+    kindle::format_pronunciations(&mut out_str, term, language);
+    kindle::format_classes(&mut out_str, term, language, layout);
+    out_str.push_str("</article>\n");
+    out_str
+}
+
+// headword with its inflected forms pointing to the entry
+fn format_search_keys(term: &Term, href: &str) -> String {
+    let mut out_str = format!(
+        "<search-key-group href=\"{}\">\n<match value=\"{}\">\n",
+        escape_xml(href), escape_xml(&term.headword),
+    );
+    for inflection in term.inflections.iter() {
+        out_str.push_str(format!("<value value=\"{}\" />\n", escape_xml(inflection)).as_str());
+    }
+    out_str.push_str("</match>\n</search-key-group>\n");
+    out_str
+}
+
+fn create_epub_nav_file(dict: &Dictionary, content_path: &str, front_matter: &[Page], files: &[Page]) -> Result<(), Box<dyn Error>> {
+    let mut body = format!("<nav epub:type=\"toc\" id=\"toc\">\n<h1>{}</h1>\n<ol>\n", escape_xml(&dict.title));
+    // all content files share one item
+    for (id, title) in front_matter.iter().chain(files.first()) {
+        body.push_str(format!("<li><a href=\"{}.xhtml\">{}</a></li>\n", id, escape_xml(title)).as_str());
+    }
+    body.push_str("</ol>\n</nav>\n");
+    fs::write(format!("{}/nav.xhtml", content_path), xhtml_page(&dict.title, &dict.ui_language, &body))?;
+    Ok(())
+}
+
+fn create_epub_opf_file(
+    dict: &Dictionary, content_path: &str, cover: &(String, &str), front_matter: &[Page], files: &[Page],
+) -> Result<(), Box<dyn Error>> {
+    let mut f = fs::File::create(format!("{}/content.opf", content_path))?;
+
+    f.write_all(format!(r#"<?xml version="1.0" encoding="utf-8"?>
+<package version="3.0" xmlns="http://www.idpf.org/2007/opf" unique-identifier="BookId">
+    <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+        <dc:identifier id="BookId">urn:uuid:{}</dc:identifier>
+        <dc:title>{}</dc:title>
+        <dc:language>{}</dc:language>
+        <dc:type>dictionary</dc:type>
+        <dc:date>{today}</dc:date>
+        <dc:description>{}</dc:description>
+        <dc:rights>{}</dc:rights>
+        <meta property="dcterms:modified">{today}T00:00:00Z</meta>
+        <meta property="source-language">{}</meta>
+        <meta property="target-language">{}</meta>
+        <meta name="cover" content="cover-image" />
+"#,
+        super::identifier(dict),
+        escape_xml(&dict.title),
+        escape_xml(&dict.source_language),
+        escape_xml(&super::description(dict)),
+        escape_xml(&kindle::rights(dict)),
+        escape_xml(&dict.source_language),
+        escape_xml(&dict.target_language),
+        today = super::today(),
+    ).as_bytes())?;
+    if !dict.author.is_empty() {
+        f.write_all(format!(r#"        <dc:creator>{author}</dc:creator>
+        <dc:publisher>{author}</dc:publisher>
+"#, author = escape_xml(&dict.author)).as_bytes())?;
+    }
+    f.write_all(format!(r#"    </metadata>
+    <manifest>
+        <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav" />
+        <item id="cover-image" href="{}" media-type="{}" properties="cover-image" />
+        <item id="search-key-map" href="{}" media-type="application/vnd.epub.search-key-map+xml" properties="search-key-map dictionary" />
+"#, escape_xml(&cover.0), cover.1, SEARCH_KEY_MAP).as_bytes())?;
+    let ids = front_matter.iter().chain(files.iter()).map(|(id, _)| id).collect::<Vec<_>>();
+    for id in ids.iter() {
+        f.write_all(format!("        <item id=\"{id}\" href=\"{id}.xhtml\" media-type=\"application/xhtml+xml\" />\n").as_bytes())?;
+    }
+
+    f.write_all("    </manifest>\n    <spine>\n".as_bytes())?;
+    for id in ids.iter() {
+        f.write_all(format!("        <itemref idref=\"{id}\" />\n").as_bytes())?;
+    }
+    f.write_all("    </spine>\n</package>\n".as_bytes())?;
+
+    Ok(())
+}
+
+// zip archive of the files, mimetype must be the first one and stored uncompressed
+fn create_zip(output_path: &str, names: &[String]) -> Result<Vec<u8>, Box<dyn Error>> {
+    // files are dated by the day of build in MS-DOS format
+    let date = super::today().split('-').map(|part| part.parse::<u16>().unwrap_or(0)).collect::<Vec<_>>();
+    let dos_date = (date[0].saturating_sub(1980) << 9) | (date[1] << 5) | date[2];
+
+    let mut out = Vec::new();
+    let mut directory = Vec::new();
+    for name in names {
+        let data = fs::read(format!("{}/{}", output_path, name))?;
+        let mut crc = Crc::new();
+        crc.update(&data);
+        let (method, compressed) = if name == "mimetype" {
+            (0u16, data.clone())
+        } else {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(&data)?;
+            (8u16, encoder.finish()?)
+        };
+        let offset = u32::try_from(out.len()).map_err(|_| "Dictionary is too large for EPUB format.")?;
+
+        // fields shared by local header and central directory
+        let mut fields = Vec::new();
+        fields.extend_from_slice(&20u16.to_le_bytes());
+        fields.extend_from_slice(&0u16.to_le_bytes());
+        fields.extend_from_slice(&method.to_le_bytes());
+        fields.extend_from_slice(&0u16.to_le_bytes());
+        fields.extend_from_slice(&dos_date.to_le_bytes());
+        fields.extend_from_slice(&crc.sum().to_le_bytes());
+        fields.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        fields.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        fields.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        fields.extend_from_slice(&0u16.to_le_bytes());
+
+        out.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        out.extend_from_slice(&fields);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&compressed);
+
+        directory.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        directory.extend_from_slice(&20u16.to_le_bytes());
+        directory.extend_from_slice(&fields);
+        // comment length, disk number, internal and external attributes
+        directory.extend_from_slice(&[0; 10]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let offset = u32::try_from(out.len()).map_err(|_| "Dictionary is too large for EPUB format.")?;
+    out.extend_from_slice(&directory);
+    out.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&(names.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(names.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+    out.extend_from_slice(&offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    Ok(out)
+}
@@ -0,0 +1,81 @@
+use crate::dictionary::{MeaningType, Term};
+
+use super::escape_json;
+
+// JSON representation of a term used by HTTP lookup API
+pub fn format_term(term: &Term) -> String {
+    let mut out_str = format!("{{\"headword\":{}", quote(&term.headword));
+
+    let mut inflections = term.inflections.iter().map(|i| i.as_str()).collect::<Vec<_>>();
+    inflections.sort();
+    out_str.push_str(format!(",\"inflections\":{}", format_list(&inflections)).as_str());
+
+    format_pronunciations(&mut out_str, term);
+    format_classes(&mut out_str, term);
+
+    out_str.push('}');
+    out_str
+}
+
+pub fn format_list(items: &[&str]) -> String {
+    let items = items.iter().map(|item| quote(item)).collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+pub fn quote(text: &str) -> String {
+    format!("\"{}\"", escape_json(text))
+}
+
+fn format_pronunciations(out_str: &mut String, term: &Term) {
+    let mut pron_keys = term.pronunciations.keys().collect::<Vec<_>>();
+    pron_keys.sort();
+    let pronunciations = pron_keys
+        .iter()
+        .map(|&name| {
+            let values = term.pronunciations.get(name).unwrap().iter().map(|p| p.as_str()).collect::<Vec<_>>();
+            format!("{}:{}", quote(name), format_list(&values))
+        })
+        .collect::<Vec<_>>();
+    out_str.push_str(format!(",\"pronunciations\":{{{}}}", pronunciations.join(",")).as_str());
+}
+
+fn format_classes(out_str: &mut String, term: &Term) {
+    let mut classes = term.classes.keys().collect::<Vec<_>>();
+    classes.sort();
+    let classes = classes
+        .iter()
+        .map(|&word_class| {
+            let meanings = term.classes.get(word_class).unwrap();
+            format!("{{\"class\":{},\"meanings\":{}}}", quote(word_class.as_str()), format_meanings(meanings))
+        })
+        .collect::<Vec<_>>();
+    out_str.push_str(format!(",\"classes\":[{}]", classes.join(",")).as_str());
+}
+
+fn format_meanings(meanings: &MeaningType) -> String {
+    let mut values = meanings.values().collect::<Vec<_>>();
+    values.sort_by_key(|a| a.order);
+    let meanings = values
+        .iter()
+        .map(|meaning| {
+            let mut translations = meaning.translations.keys().map(|t| t.as_str()).collect::<Vec<_>>();
+            translations.sort();
+            // source ids of each translation
+            let sources = translations
+                .iter()
+                .map(|&translation| {
+                    let mut sources = meaning.translations[translation].iter().map(|s| s.as_str()).collect::<Vec<_>>();
+                    sources.sort();
+                    format!("{}:{}", quote(translation), format_list(&sources))
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "{{\"description\":{},\"translations\":{},\"sources\":{{{}}}}}",
+                quote(&meaning.description),
+                format_list(&translations),
+                sources.join(","),
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", meanings.join(","))
+}
@@ -1,48 +1,41 @@
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::error::Error;
 use std::fs;
 use std::io::Write;
 
-use crate::dictionary::{Dictionary, MeaningType, Term};
+use crate::cli_config::EntryLayout;
+use crate::dictionary::localization::{heading, text, Heading, Text};
+use crate::dictionary::{localization, Dictionary, MeaningType, Term, WordClass};
 
 use super::escape_xml;
 
 pub fn to_kindle(dict: &Dictionary, output_path: &str, force: bool) -> Result<(), Box<dyn Error>> {
-    let output = fs::metadata(output_path);
-    match output {
-        Ok(metadata) => {
-            if metadata.is_file() {
-                Err(format!("{} is a file, a directory expected.", output_path))?;
-            } else if metadata.is_dir() && !force {
-                Err(format!("{} is an existing directory, use -f to force.", output_path))?;
-            }
-        },
-
-        Err(_) => {
-            fs::create_dir_all(output_path)?;
-        }
-    }
+    super::prepare_output_dir(output_path, force)?;
 
+    let cover = super::cover::create_cover(dict, output_path)?;
+    let front_matter = create_kindle_front_matter(dict, output_path)?;
     let files = create_kindle_content_files(dict, output_path)?;
-    create_kindle_opf_file(dict, output_path, &files)?;
+    create_kindle_opf_file(dict, output_path, &cover, &front_matter, &files)?;
+    super::validator::validate_package(output_path, "content.opf")?;
 
     Ok(())
 }
 
 fn create_kindle_content_files(dict: &Dictionary, output_path: &str) -> Result<Vec<(String, String)>, Box<dyn Error>> {
-    let keys = dict.terms.keys().collect::<Vec<_>>();
+    // homographs are separate entries sharing the same index
+    let terms = dict.all_terms().collect::<Vec<_>>();
     let mut pos: usize = 0;
     let batch_size: usize = 30_000;
     let mut files = Vec::<(String, String)>::new();
     let mut i = 1;
-    while pos < keys.len() {
+    while pos < terms.len() {
         let mut max = pos + batch_size;
-        if max > keys.len() {
-            max = keys.len();
+        if max > terms.len() {
+            max = terms.len();
         }
         let id = format!("content{:04}", i);
         let path = format!("{}/{}.xhtml", output_path, id);
-        create_kindle_content_file(dict, &keys[pos..max], path.as_str())?;
+        create_kindle_content_file(dict, &terms[pos..max], path.as_str())?;
         files.push((id, path));
         pos += batch_size;
         i += 1;
@@ -50,29 +43,17 @@ fn create_kindle_content_files(dict: &Dictionary, output_path: &str) -> Result<V
     Ok(files)
 }
 
-fn create_kindle_content_file(dict: &Dictionary, keys: &[&String], content_file_path: &str) -> Result<(), Box<dyn Error>> {
+fn create_kindle_content_file(dict: &Dictionary, terms: &[&Term], content_file_path: &str) -> Result<(), Box<dyn Error>> {
     let mut f = fs::File::create(content_file_path)?;
 
     start_kindle_content_file(&mut f)?;
 
-    for &key in keys.iter() {
-        let term = dict.terms.get(key).unwrap();
-
+    for &term in terms.iter() {
         if term.is_empty() {
             continue;
         }
 
-        let mut out_str = r#"
-        <idx:entry name="main" scriptable="yes" spell="yes">
-"#.to_string();
-
-        format_headword(&mut out_str, term);
-        format_pronunciations(&mut out_str, term);
-        format_classes(&mut out_str, term);
-
-        out_str.push_str("\n</idx:entry>\n");
-
-        f.write_all(out_str.as_bytes())?;
+        f.write_all(format_entry(term, &dict.ui_language, &dict.entry_layout).as_bytes())?;
     }
 
     end_kindle_content_file(&mut f)?;
@@ -80,6 +61,21 @@ fn create_kindle_content_file(dict: &Dictionary, keys: &[&String], content_file_
     Ok(())
 }
 
+// kindle markup of one dictionary entry with labels in given language
+pub fn format_entry(term: &Term, language: &str, layout: &EntryLayout) -> String {
+    let mut out_str = r#"
+        <idx:entry name="main" scriptable="yes" spell="yes">
+"#.to_string();
+
+    format_headword(&mut out_str, term);
+    format_pronunciations(&mut out_str, term, language);
+    format_classes(&mut out_str, term, language, layout);
+
+    out_str.push_str("\n</idx:entry>\n");
+
+    out_str
+}
+
 fn format_headword(out_str: &mut String, term: &Term) {
     out_str.push_str(format!("<b><idx:orth>{}", super::escape_xml(&term.headword)).as_str());
     if !term.inflections.is_empty() {
@@ -92,7 +88,7 @@ fn format_headword(out_str: &mut String, term: &Term) {
     out_str.push_str("</idx:orth></b><br />");
 }
 
-fn format_pronunciations(out_str: &mut String, term: &Term) {
+pub fn format_pronunciations(out_str: &mut String, term: &Term, language: &str) {
     let mut pron_keys = term.pronunciations.keys().collect::<Vec<_>>();
     pron_keys.sort();
     for name in pron_keys {
@@ -102,14 +98,14 @@ fn format_pronunciations(out_str: &mut String, term: &Term) {
         }
         let pronunciations = term.pronunciations.get(name).unwrap();
         if !name.is_empty() && name != "wiki" {
-            out_str.push_str(format!("<i>{}</i>: ", name).as_str());
+            out_str.push_str(format!("<i>{}</i>: ", escape_xml(localization::pronunciation_name(language, name))).as_str());
         }
         out_str.push_str(escape_xml(pronunciations.join(", ").as_str()).as_str());
         out_str.push_str("<br />\n");
     }
 }
 
-fn format_classes(out_str: &mut String, term: &Term) {
+pub fn format_classes(out_str: &mut String, term: &Term, language: &str, layout: &EntryLayout) {
     let mut classes = term.classes.keys().collect::<Vec<_>>();
     classes.sort();
     for word_class in classes {
@@ -118,15 +114,18 @@ fn format_classes(out_str: &mut String, term: &Term) {
             continue;
         }
 
-        out_str.push_str(word_class.as_str());
-        format_meanings(out_str, meanings);
+        out_str.push_str(localization::word_class(language, word_class));
+        match layout {
+            EntryLayout::Condensed => format_meanings(out_str, meanings),
+            EntryLayout::Meanings => format_meaning_translations(out_str, meanings),
+        }
     }
 }
 
 fn format_meanings(out_str: &mut String, meanings: &MeaningType) {
     let mut translations = HashSet::new();
     for meaning in meanings.values() {
-        for translation in &meaning.translations {
+        for translation in meaning.translations.keys() {
             translations.insert(translation);
         }
     }
@@ -138,7 +137,7 @@ fn format_meanings(out_str: &mut String, meanings: &MeaningType) {
 
     out_str.push_str("<ol>\n");
     let mut values = meanings.values().collect::<Vec<_>>();
-    values.sort_by(|a, b| a.order.cmp(&b.order));
+    values.sort_by_key(|a| a.order);
     for meaning in values {
         if meaning.description.is_empty() {
             continue;
@@ -148,12 +147,171 @@ fn format_meanings(out_str: &mut String, meanings: &MeaningType) {
     out_str.push_str("</ol>\n");
 }
 
+// numbered meanings, each gloss followed by its own translations
+fn format_meaning_translations(out_str: &mut String, meanings: &MeaningType) {
+    out_str.push_str("<ol>\n");
+    let mut values = meanings.values().collect::<Vec<_>>();
+    values.sort_by_key(|a| a.order);
+    for meaning in values {
+        let mut translations = meaning.translations.keys().map(|t| t.as_str()).collect::<Vec<_>>();
+        translations.sort();
+        let translations = escape_xml(translations.join(", ").as_str());
+        let line = match (meaning.description.is_empty(), translations.is_empty()) {
+            (true, true) => continue,
+            (true, false) => translations,
+            (false, true) => escape_xml(&meaning.description),
+            (false, false) => format!("<i>{}</i>: {}", escape_xml(&meaning.description), translations),
+        };
+        out_str.push_str(format!("<li>{}</li>\n", line).as_str());
+    }
+    out_str.push_str("</ol>\n");
+}
+
 fn format_translations(out_str: &mut String, translations: &HashSet<&String>) {
     let transl: Vec<&str> = translations.iter().map(|string| string.as_str()).collect();
 
     out_str.push_str(format!("<li>{}</li>\n", escape_xml(transl.join(" | ").as_str())).as_str());
 }
 
+// page before entries as (id, guide type, title)
+type FrontMatterPage = (String, String, String);
+
+// pages before entries as (id, guide type, title, body)
+pub fn front_matter_pages(dict: &Dictionary) -> [(&'static str, &'static str, &str, String); 4] {
+    let language = dict.ui_language.as_str();
+    [
+        ("title", "title-page", dict.title.as_str(), format_title_page(dict)),
+        ("usage", "preface", heading(language, &Heading::HowToUse), format_usage_page(dict)),
+        ("abbreviations", "glossary", heading(language, &Heading::Abbreviations), format_abbreviations_page(dict)),
+        ("licence", "copyright-page", heading(language, &Heading::Licence), format_licence_page(dict)),
+    ]
+}
+
+fn create_kindle_front_matter(dict: &Dictionary, output_path: &str) -> Result<Vec<FrontMatterPage>, Box<dyn Error>> {
+    let mut front_matter = vec![];
+    for (id, guide_type, title, body) in front_matter_pages(dict) {
+        let mut f = fs::File::create(format!("{}/{}.xhtml", output_path, id))?;
+        f.write_all(format!(r#"<html>
+<head>
+    <meta http-equiv="Content-Type" content="text/html; charset=utf-8" />
+    <title>{}</title>
+</head>
+<body>
+{}</body>
+</html>
+"#, escape_xml(title), body).as_bytes())?;
+        front_matter.push((id.to_string(), guide_type.to_string(), title.to_string()));
+    }
+
+    Ok(front_matter)
+}
+
+// title, author, languages, date of build and versions of sources
+fn format_title_page(dict: &Dictionary) -> String {
+    let language = dict.ui_language.as_str();
+    let mut body = format!("<h1>{}</h1>\n", escape_xml(&dict.title));
+    if !dict.author.is_empty() {
+        body.push_str(format!("<p>{}: {}</p>\n", escape_xml(heading(language, &Heading::Author)), escape_xml(&dict.author)).as_str());
+    }
+    body.push_str(format!(
+        "<p>{}: {}<br />\n{}: {}<br />\n{}: {}<br />\n{}: {}</p>\n",
+        escape_xml(heading(language, &Heading::SourceLanguage)), dict.source_language,
+        escape_xml(heading(language, &Heading::TargetLanguage)), dict.target_language,
+        escape_xml(heading(language, &Heading::Records)), dict.non_empty_len(),
+        escape_xml(heading(language, &Heading::Date)), super::today(),
+    ).as_str());
+
+    let sources = dict.used_sources();
+    if !sources.is_empty() {
+        body.push_str(format!("<h3>{}</h3>\n<p>", escape_xml(heading(language, &Heading::Sources))).as_str());
+        let sources = sources.iter().map(|source| escape_xml(&source.attribution())).collect::<Vec<_>>();
+        body.push_str(&sources.join("<br />\n"));
+        body.push_str("</p>\n");
+    }
+    body
+}
+
+fn format_usage_page(dict: &Dictionary) -> String {
+    let language = dict.ui_language.as_str();
+    format!(
+        "<h2>{}</h2>\n<p>{}</p>\n<p>{}</p>\n",
+        escape_xml(heading(language, &Heading::HowToUse)),
+        escape_xml(text(language, &Text::UsageLookup)),
+        escape_xml(text(language, &Text::UsageEntry)),
+    )
+}
+
+// labels of word classes and pronunciations found in entries with explanation
+fn format_abbreviations_page(dict: &Dictionary) -> String {
+    let language = dict.ui_language.as_str();
+    let mut body = format!("<h2>{}</h2>\n", escape_xml(heading(language, &Heading::Abbreviations)));
+
+    let terms = dict.all_terms().filter(|term| !term.is_empty()).collect::<Vec<_>>();
+    let classes = terms.iter().flat_map(|term| term.classes.keys()).collect::<BTreeSet<_>>();
+    if !classes.is_empty() {
+        body.push_str(format!("<h3>{}</h3>\n<p>", escape_xml(heading(language, &Heading::WordClasses))).as_str());
+        let labels = classes
+            .iter()
+            .map(|&word_class| match word_class {
+                WordClass::Unknown => format!(
+                    "<b>{}</b> – {}",
+                    escape_xml(localization::word_class(language, word_class)),
+                    escape_xml(text(language, &Text::UnknownWordClass)),
+                ),
+                _ => format!("<b>{}</b>", escape_xml(localization::word_class(language, word_class))),
+            })
+            .collect::<Vec<_>>();
+        body.push_str(&labels.join("<br />\n"));
+        body.push_str("</p>\n");
+    }
+
+    let names = terms
+        .iter()
+        .flat_map(|term| term.pronunciations.keys())
+        .filter(|name| !name.is_empty())
+        .collect::<BTreeSet<_>>();
+    if !names.is_empty() {
+        body.push_str(format!("<h3>{}</h3>\n<p>", escape_xml(heading(language, &Heading::Pronunciations))).as_str());
+        let labels = names
+            .iter()
+            .map(|&name| {
+                let description = match localization::pronunciation_name(language, name) {
+                    _ if name == "wiki" => text(language, &Text::WikiPronunciation),
+                    translated if translated != name => translated,
+                    _ => "",
+                };
+                if description.is_empty() {
+                    format!("<b>{}</b>", escape_xml(name))
+                } else {
+                    format!("<b>{}</b> – {}", escape_xml(name), escape_xml(description))
+                }
+            })
+            .collect::<Vec<_>>();
+        body.push_str(&labels.join("<br />\n"));
+        body.push_str("</p>\n");
+    }
+
+    body
+}
+
+// attribution and licences of sources used in the dictionary
+fn format_licence_page(dict: &Dictionary) -> String {
+    let mut body = format!("<h2>{}</h2>\n", escape_xml(heading(&dict.ui_language, &Heading::Licence)));
+    for source in dict.used_sources() {
+        body.push_str(format!("<p>{}<br />\n", escape_xml(&source.attribution())).as_str());
+        if source.licence_url().is_empty() {
+            body.push_str(format!("{}</p>\n", escape_xml(source.licence_name())).as_str());
+        } else {
+            body.push_str(format!(
+                "<a href=\"{url}\">{}</a><br />\n{url}</p>\n",
+                escape_xml(source.licence_name()),
+                url = escape_xml(source.licence_url()),
+            ).as_str());
+        }
+    }
+    body
+}
+
 fn start_kindle_content_file(f: &mut fs::File) -> Result<(), Box<dyn Error>> {
     f.write_all(r#"<html xmlns:math="http://exslt.org/math" xmlns:svg="http://www.w3.org/2000/svg"
     xmlns:tl="https://kindlegen.s3.amazonaws.com/AmazonKindlePublishingGuidelines.pdf" xmlns:saxon="http://saxon.sf.net/"
@@ -183,27 +341,45 @@ fn end_kindle_content_file(f: &mut fs::File) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn create_kindle_opf_file(dict: &Dictionary, output_path: &str, files: &Vec<(String, String)>) -> Result<(), Box<dyn Error>> {
+fn create_kindle_opf_file(
+    dict: &Dictionary, output_path: &str, cover: &(String, &str), front_matter: &[FrontMatterPage], files: &[(String, String)],
+) -> Result<(), Box<dyn Error>> {
     let opf_file_path = format!("{}/content.opf", output_path);
     let mut f = fs::File::create(opf_file_path)?;
 
-    f.write_all(format!(r#"<?xml version="1.0"?>
-<package version="2.0" xmlns="http://www.idpf.org/2007/opf" unique-identifier="en-cs-dict">
-    <metadata>
+    f.write_all(format!(r#"<?xml version="1.0" encoding="utf-8"?>
+<package version="2.0" xmlns="http://www.idpf.org/2007/opf" unique-identifier="BookId">
+    <metadata xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:opf="http://www.idpf.org/2007/opf">
+        <dc:identifier id="BookId" opf:scheme="UUID">urn:uuid:{}</dc:identifier>
         <dc:title>{}</dc:title>
-        <dc:creator opf:role="aut">{}</dc:creator>
         <dc:language>{}</dc:language>
-        <meta name="cover" content="my-cover-image" />
+        <dc:date>{}</dc:date>
+        <dc:description>{}</dc:description>
+        <dc:rights>{}</dc:rights>
+"#,
+        super::identifier(dict),
+        escape_xml(&dict.title),
+        escape_xml(&dict.source_language),
+        super::today(),
+        escape_xml(&super::description(dict)),
+        escape_xml(&rights(dict)),
+    ).as_bytes())?;
+    if !dict.author.is_empty() {
+        f.write_all(format!(r#"        <dc:creator opf:role="aut">{author}</dc:creator>
+        <dc:publisher>{author}</dc:publisher>
+"#, author = escape_xml(&dict.author)).as_bytes())?;
+    }
+    f.write_all(format!(r#"        <meta name="cover" content="my-cover-image" />
         <x-metadata>
           <DictionaryInLanguage>{}</DictionaryInLanguage>
           <DictionaryOutLanguage>{}</DictionaryOutLanguage>
         </x-metadata>
     </metadata>
     <manifest>
-        <item href="dict.png" id="my-cover-image" media-type="image/png" />
-"#, dict.title, dict.author, /* dict.source_language */ "en", dict.source_language, dict.target_language).as_bytes())?;
-    for file in files {
-        let id = &file.0;
+        <item href="{}" id="my-cover-image" media-type="{}" />
+"#, escape_xml(&dict.source_language), escape_xml(&dict.target_language), escape_xml(&cover.0), cover.1).as_bytes())?;
+    let ids = front_matter.iter().map(|page| &page.0).chain(files.iter().map(|file| &file.0)).collect::<Vec<_>>();
+    for id in ids.iter() {
         f.write_all(format!("<item id=\"{id}\" href=\"{id}.xhtml\" media-type=\"application/xhtml+xml\" />\n").as_bytes())?;
     }
 
@@ -212,15 +388,35 @@ fn create_kindle_opf_file(dict: &Dictionary, output_path: &str, files: &Vec<(Str
     <spine>
 "#.as_bytes())?;
 
-    for file in files {
-        let id = &file.0;
+    for id in ids.iter() {
         f.write_all(format!("<itemref idref=\"{id}\"/>\n").as_bytes())?;
     }
 
     f.write_all(r#"
     </spine>
+    <guide>
+"#.as_bytes())?;
+
+    for (id, guide_type, title) in front_matter {
+        f.write_all(format!("<reference type=\"{guide_type}\" title=\"{}\" href=\"{id}.xhtml\" />\n", escape_xml(title)).as_bytes())?;
+    }
+    if let Some((id, _)) = files.first() {
+        f.write_all(format!("<reference type=\"text\" title=\"{}\" href=\"{id}.xhtml\" />\n", escape_xml(&dict.title)).as_bytes())?;
+    }
+
+    f.write_all(r#"
+    </guide>
 </package>
 "#.as_bytes())?;
 
     Ok(())
 }
+
+// licences of used sources e.g. Wiktionary contributors: Creative Commons Attribution-ShareAlike 4.0
+pub fn rights(dict: &Dictionary) -> String {
+    dict.used_sources()
+        .iter()
+        .map(|source| format!("{}: {}", source.attribution(), source.licence_name()))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,247 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+
+const SEARCH_KEY_MAP_TYPE: &str = "application/vnd.epub.search-key-map+xml";
+
+#[derive(Default)]
+struct Package {
+    version: String,
+    unique_identifier: Option<String>,
+    identifiers: Vec<Option<String>>,
+    // metadata element -> its text
+    metadata: HashMap<String, String>,
+    cover: Option<String>,
+    modified: bool,
+    nav: bool,
+    // manifest id -> href and media type
+    items: HashMap<String, (String, String)>,
+    itemrefs: Vec<String>,
+    references: Vec<String>,
+    problems: Vec<String>,
+}
+
+// checks the unpacked EPUB: mimetype, container pointing to the package and the package itself
+pub fn validate_epub(output_path: &str) -> Result<(), Box<dyn Error>> {
+    let mimetype = fs::read_to_string(format!("{}/mimetype", output_path))?;
+    if mimetype != "application/epub+zip" {
+        Err(format!("Invalid mimetype {}", mimetype))?;
+    }
+
+    let container = format!("{}/META-INF/container.xml", output_path);
+    let mut reader = Reader::from_file(&container)?;
+    let mut buf = Vec::new();
+    let mut rootfile = None;
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(err) => Err(format!("Cannot parse {}: {}", container, err))?,
+            Ok(Event::Eof) => break,
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) if e.name().as_ref() == b"rootfile" => {
+                rootfile = rootfile.or(attribute(&e, "full-path"));
+            },
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    match rootfile {
+        Some(rootfile) => validate_package(output_path, &rootfile),
+        None => Err(format!("No rootfile in {}", container))?,
+    }
+}
+
+// checks the generated package so errors show before kindlegen or an e-reader opens it
+pub fn validate_package(output_path: &str, opf_file_name: &str) -> Result<(), Box<dyn Error>> {
+    let package = read_package(&format!("{}/{}", output_path, opf_file_name))?;
+    let mut problems = package.problems.clone();
+    // hrefs are relative to the package file
+    let base_path = match opf_file_name.rsplit_once('/') {
+        Some((dir, _)) => format!("{}/{}", output_path, dir),
+        None => output_path.to_string(),
+    };
+
+    match &package.unique_identifier {
+        None => problems.push("package has no unique-identifier".to_string()),
+        Some(id) if !package.identifiers.iter().any(|identifier| identifier.as_ref() == Some(id)) => {
+            problems.push(format!("no dc:identifier with id {}", id));
+        },
+        _ => (),
+    }
+    for element in ["dc:title", "dc:language", "dc:identifier"] {
+        if package.metadata.get(element).is_none_or(|text| text.trim().is_empty()) {
+            problems.push(format!("missing {}", element));
+        }
+    }
+    if package.version.starts_with('3') && !package.modified {
+        problems.push("missing dcterms:modified".to_string());
+    }
+    if package.version.starts_with('3') && !package.nav {
+        problems.push("missing navigation document".to_string());
+    }
+    if let Some(cover) = &package.cover {
+        if !package.items.contains_key(cover) {
+            problems.push(format!("cover {} is not in manifest", cover));
+        }
+    }
+
+    let hrefs = package.items.values().map(|(href, _)| href.as_str()).collect::<HashSet<_>>();
+    // ids of elements in each document for links with fragment
+    let mut anchors = HashMap::new();
+    let mut ids = package.items.iter().collect::<Vec<_>>();
+    ids.sort();
+    for (id, (href, media_type)) in ids {
+        let path = format!("{}/{}", base_path, href);
+        if !fs::exists(&path)? {
+            problems.push(format!("manifest item {} points to missing file {}", id, href));
+        } else if media_type == "application/xhtml+xml" || media_type == SEARCH_KEY_MAP_TYPE {
+            match check_well_formed(&path) {
+                Ok(ids) => _ = anchors.insert(href.as_str(), ids),
+                Err(err) => problems.push(format!("{} is not well-formed: {}", href, err)),
+            }
+        }
+    }
+    if package.itemrefs.is_empty() {
+        problems.push("spine is empty".to_string());
+    }
+    for idref in package.itemrefs.iter().filter(|idref| !package.items.contains_key(*idref)) {
+        problems.push(format!("spine refers to {} which is not in manifest", idref));
+    }
+    for href in package.references.iter().filter(|href| !hrefs.contains(href.split('#').next().unwrap_or(""))) {
+        problems.push(format!("guide refers to {} which is not in manifest", href));
+    }
+
+    let mut maps = package.items.values().filter(|(_, media_type)| media_type == SEARCH_KEY_MAP_TYPE).collect::<Vec<_>>();
+    maps.sort();
+    for (href, _) in maps.into_iter().filter(|(href, _)| anchors.contains_key(href.as_str())) {
+        let missing = search_key_hrefs(&format!("{}/{}", base_path, href))?
+            .into_iter()
+            .filter(|link| {
+                let (file, fragment) = link.split_once('#').unwrap_or((link, ""));
+                anchors.get(file).is_none_or(|ids| !fragment.is_empty() && !ids.contains(fragment))
+            })
+            .collect::<Vec<_>>();
+        if let Some(first) = missing.first() {
+            problems.push(format!("{} has {} links to missing entries e.g. {}", href, missing.len(), first));
+        }
+    }
+
+    if !problems.is_empty() {
+        Err(format!("Invalid package {}:\n    {}", opf_file_name, problems.join("\n    ")))?;
+    }
+    Ok(())
+}
+
+fn read_package(path: &str) -> Result<Package, Box<dyn Error>> {
+    let mut reader = Reader::from_file(path)?;
+    let mut buf = Vec::new();
+    let mut package = Package::default();
+    let mut current: Option<String> = None;
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(err) => Err(format!("Cannot parse {}: {}", path, err))?,
+            Ok(Event::Eof) => break,
+            Ok(Event::Start(e)) => {
+                current = Some(String::from_utf8_lossy(e.name().as_ref()).to_string());
+                read_element(&mut package, &e);
+            },
+            Ok(Event::Empty(e)) => read_element(&mut package, &e),
+            Ok(Event::Text(e)) => {
+                if let Some(element) = current.as_ref().filter(|element| element.starts_with("dc:")) {
+                    package.metadata.entry(element.clone()).or_default().push_str(&e.decode()?);
+                }
+            },
+            Ok(Event::End(_)) => current = None,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    Ok(package)
+}
+
+fn attribute(e: &BytesStart, name: &str) -> Option<String> {
+    e.try_get_attribute(name)
+        .ok()
+        .flatten()
+        .and_then(|attribute| attribute.unescape_value().ok().map(|value| value.to_string()))
+}
+
+fn read_element(package: &mut Package, e: &BytesStart) {
+    let attribute = |name: &str| attribute(e, name);
+
+    match e.name().as_ref() {
+        b"package" => {
+            package.version = attribute("version").unwrap_or_default();
+            package.unique_identifier = attribute("unique-identifier");
+        },
+        b"dc:identifier" => package.identifiers.push(attribute("id")),
+        b"meta" if attribute("name").as_deref() == Some("cover") => package.cover = attribute("content"),
+        b"meta" if attribute("property").as_deref() == Some("dcterms:modified") => package.modified = true,
+        b"item" => match (attribute("id"), attribute("href"), attribute("media-type")) {
+            (Some(id), Some(href), Some(media_type)) => {
+                if attribute("properties").is_some_and(|properties| properties.split_whitespace().any(|p| p == "nav")) {
+                    package.nav = true;
+                }
+                if package.items.insert(id.clone(), (href, media_type)).is_some() {
+                    package.problems.push(format!("duplicate manifest id {}", id));
+                }
+            },
+            _ => package.problems.push("manifest item needs id, href and media-type".to_string()),
+        },
+        b"itemref" => match attribute("idref") {
+            Some(idref) => package.itemrefs.push(idref),
+            None => package.problems.push("spine itemref without idref".to_string()),
+        },
+        b"reference" => match attribute("href") {
+            Some(href) => package.references.push(href),
+            None => package.problems.push("guide reference without href".to_string()),
+        },
+        _ => (),
+    }
+}
+
+// returns ids of elements in the document
+fn check_well_formed(path: &str) -> Result<HashSet<String>, Box<dyn Error>> {
+    let mut reader = Reader::from_file(path)?;
+    let mut buf = Vec::new();
+    let mut depth = 0;
+    let mut ids = HashSet::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Eof => break,
+            Event::Start(e) => {
+                depth += 1;
+                ids.extend(attribute(&e, "id"));
+            },
+            Event::Empty(e) => ids.extend(attribute(&e, "id")),
+            Event::End(_) => depth -= 1,
+            _ => (),
+        }
+        buf.clear();
+    }
+    if depth != 0 {
+        Err("unclosed elements")?;
+    }
+    Ok(ids)
+}
+
+fn search_key_hrefs(path: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut reader = Reader::from_file(path)?;
+    let mut buf = Vec::new();
+    let mut hrefs = vec![];
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Eof => break,
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref() == b"search-key-group" => {
+                hrefs.extend(attribute(&e, "href"));
+            },
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(hrefs)
+}
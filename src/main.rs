@@ -1,6 +1,8 @@
 mod cli_config;
 mod dictionary;
 mod download;
+mod repl;
+mod server;
 
 use std::{env, process};
 use std::error::Error;
@@ -27,8 +29,12 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     println!("{:#?}", &config);
 
-    if config.query.is_none() && config.output_path.is_none() {
-        Err("No search (-s) or output path (-o) is specified.")?;
+    if config.query.is_none()
+        && !config.interactive
+        && config.output_path.is_none()
+        && config.dict_server.is_none()
+        && config.http_server.is_none() {
+        Err("No search (-s, -r), output path (-o) or server (-ds, -hs) is specified.")?;
     }
 
     let dict = Dictionary::build(&config)?;
@@ -36,24 +42,45 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("Records: {}", dict.len());
     println!("Non-empty records: {}", dict.non_empty_len());
     println!("Translated records: {}", dict.translations_len());
+    for (source, translations, pronunciations) in dict.source_statistics() {
+        println!("Source {}: {} translations, {} pronunciations", source, translations, pronunciations);
+    }
 
     if let Some(query) = &config.query {
         println!("{:#?}", dict.lookup(query));
+        for term in dict.lookup_inflection(query) {
+            println!("{} is a form of:", query);
+            println!("{:#?}", term);
+        }
     }
 
     if let Some(output_path) = &config.output_path {
-        dict.to_kindle(output_path, config.force)?;
+        dict.write(output_path, &config.output_format, config.force)?;
     }
 
+    let mut dictionaries = vec![dict];
+
     if let Some(reverse_output_path) = &config.reverse_output_path {
         if let Some(reverse_title) = &config.reverse_title {
-            let reversed_dict = dict.reverse(reverse_title);
+            let reversed_dict = dictionaries[0].reverse(reverse_title, &config)?;
             println!("Records in reversed dictionary: {}", reversed_dict.len());
-            reversed_dict.to_kindle(reverse_output_path, config.force)?;
+            reversed_dict.write(reverse_output_path, &config.output_format, config.force)?;
+            dictionaries.push(reversed_dict);
         } else {
             Err("No reverse title (-rt) is specified.")?;
         }
     }
 
+    if config.interactive {
+        repl::run(&dictionaries)?;
+    }
+
+    match (&config.dict_server, &config.http_server) {
+        (Some(_), Some(_)) => Err("Only one server (-ds or -hs) can run at a time.")?,
+        (Some(address), None) => server::dict::serve(address, dictionaries)?,
+        (None, Some(address)) => server::http::serve(address, dictionaries)?,
+        (None, None) => (),
+    }
+
     Ok(())
 }
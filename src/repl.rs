@@ -0,0 +1,97 @@
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+
+use crate::dictionary::{Dictionary, SearchStrategy};
+
+const MAX_DISTANCE: usize = 2;
+const LIMIT: usize = 20;
+
+const HELP: &str = r#"word          look up the word, inflected forms are resolved to their headwords
+prefix*       list headwords starting with the prefix
+:db           list loaded dictionaries
+:db name      switch to dictionary with given name e.g. :db cs-en
+:help         this help
+:quit         exit
+"#;
+
+// interactive lookup shell over loaded dictionaries
+pub fn run(dictionaries: &[Dictionary]) -> Result<(), Box<dyn Error>> {
+    let Some(mut dict) = dictionaries.first() else {
+        return Ok(());
+    };
+
+    println!("Type a word to look it up or :help for help.");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}> ", dict.name());
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+        let line = line?;
+        let input = line.trim();
+
+        match input.split_once(' ').unwrap_or((input, "")) {
+            ("", _) => continue,
+            (":quit" | ":q", _) => break,
+            (":help" | ":h", _) => print!("{}", HELP),
+            (":db", "") => {
+                for d in dictionaries {
+                    println!("{}\t{}", d.name(), d.title());
+                }
+            },
+            (":db", name) => match dictionaries.iter().find(|d| d.name() == name.trim()) {
+                Some(d) => dict = d,
+                None => println!("Unknown dictionary: {}", name.trim()),
+            },
+            _ => match input.strip_suffix('*') {
+                Some(prefix) => complete(dict, prefix),
+                None => lookup(dict, input),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+fn lookup(dict: &Dictionary, word: &str) {
+    let terms = dict.search(word, SearchStrategy::Exact);
+    for term in terms.iter() {
+        println!("{}", term.to_plain_text(dict.ui_language()));
+    }
+
+    let lemmas = dict.lookup_inflection(word);
+    for term in lemmas.iter() {
+        println!("{} is a form of:\n", word);
+        println!("{}", term.to_plain_text(dict.ui_language()));
+    }
+
+    if !terms.is_empty() || !lemmas.is_empty() {
+        return;
+    }
+
+    let suggestions = dict.suggest(word, MAX_DISTANCE, LIMIT);
+    if suggestions.is_empty() {
+        println!("No match found.");
+    } else {
+        let headwords = suggestions.iter().map(|term| term.headword()).collect::<Vec<_>>();
+        println!("Did you mean: {}", headwords.join(", "));
+    }
+}
+
+fn complete(dict: &Dictionary, prefix: &str) {
+    let terms = dict.search(prefix, SearchStrategy::Prefix);
+    for term in terms.iter().take(LIMIT) {
+        println!("{}", term.headword());
+    }
+
+    if terms.len() > LIMIT {
+        println!("... and {} more", terms.len() - LIMIT);
+    } else if terms.is_empty() {
+        println!("No match found.");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod dict;
+pub mod http;
@@ -0,0 +1,325 @@
+use std::error::Error;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process;
+use std::sync::Arc;
+use std::thread;
+
+use crate::dictionary::{Dictionary, SearchStrategy};
+
+const STRATEGIES: [(&str, &str, SearchStrategy); 3] = [
+    ("exact", "Match headwords exactly", SearchStrategy::Exact),
+    ("prefix", "Match prefixes", SearchStrategy::Prefix),
+    ("substring", "Match substring occurring anywhere in a headword", SearchStrategy::Substring),
+];
+
+const HELP: &str = r#"DEFINE database word         -- look up word in database
+MATCH database strategy word -- match word in database using strategy
+SHOW DB                      -- list all accessible databases
+SHOW DATABASES               -- list all accessible databases
+SHOW STRAT                   -- list available matching strategies
+SHOW STRATEGIES              -- list available matching strategies
+SHOW INFO database           -- provide information about the database
+SHOW SERVER                  -- provide site-specific information
+OPTION MIME                  -- use MIME headers
+CLIENT info                  -- identify client to server
+STATUS                       -- display timing information
+HELP                         -- display this help information
+QUIT                         -- terminate connection
+"#;
+
+// RFC 2229 server answering queries from given dictionaries
+pub fn serve(address: &str, dictionaries: Vec<Dictionary>) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(address)?;
+    let dictionaries = Arc::new(dictionaries);
+
+    println!("DICT server listening on: {}", listener.local_addr()?);
+
+    for (connection_id, stream) in listener.incoming().enumerate() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("Connection failed: {err}");
+                continue;
+            }
+        };
+
+        let dictionaries = Arc::clone(&dictionaries);
+        thread::spawn(move || {
+            let mut session = Session::new(&dictionaries, connection_id);
+            if let Err(err) = session.handle(stream) {
+                eprintln!("Connection error: {err}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+struct Session<'a> {
+    dictionaries: &'a [Dictionary],
+    connection_id: usize,
+    mime: bool,
+}
+
+impl<'a> Session<'a> {
+    fn new(dictionaries: &'a [Dictionary], connection_id: usize) -> Session<'a> {
+        Session {
+            dictionaries,
+            connection_id,
+            mime: false,
+        }
+    }
+
+    fn handle(&mut self, stream: TcpStream) -> Result<(), Box<dyn Error>> {
+        let reader = BufReader::new(stream.try_clone()?);
+        let mut out = stream;
+
+        let banner = format!(
+            "220 dictionary DICT server <mime> <{}.{}@dictionary>",
+            self.connection_id,
+            process::id(),
+        );
+        write_line(&mut out, &banner)?;
+
+        for line in reader.lines() {
+            let line = line?;
+            let args = split_command(&line);
+            if args.is_empty() {
+                continue;
+            }
+
+            let command = args[0].to_lowercase();
+            let sub_command = args.get(1).map(|arg| arg.to_lowercase()).unwrap_or_default();
+            match (command.as_str(), sub_command.as_str()) {
+                ("define", _) if args.len() == 3 => self.define(&mut out, &args[1], &args[2])?,
+                ("match", _) if args.len() == 4 => self.match_word(&mut out, &args[1], &args[2], &args[3])?,
+                ("show", "db" | "databases") if args.len() == 2 => self.show_databases(&mut out)?,
+                ("show", "strat" | "strategies") if args.len() == 2 => self.show_strategies(&mut out)?,
+                ("show", "info") if args.len() == 3 => self.show_info(&mut out, &args[2])?,
+                ("show", "server") if args.len() == 2 => self.show_server(&mut out)?,
+                ("option", "mime") if args.len() == 2 => {
+                    self.mime = true;
+                    write_line(&mut out, "250 ok - using MIME headers")?;
+                },
+                ("client", _) => write_line(&mut out, "250 ok")?,
+                ("status", _) => write_line(&mut out, "210 status: ok")?,
+                ("help", _) => {
+                    write_line(&mut out, "113 help text follows")?;
+                    self.write_text(&mut out, HELP)?;
+                    write_line(&mut out, "250 ok")?;
+                },
+                ("quit", _) => {
+                    write_line(&mut out, "221 bye")?;
+                    break;
+                },
+                ("auth" | "sasl" | "saslauth", _) => write_line(&mut out, "502 command not implemented")?,
+                ("define" | "match" | "show" | "option", _) => write_line(&mut out, "501 syntax error, illegal parameters")?,
+                _ => write_line(&mut out, "500 unknown command")?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn define(&self, out: &mut TcpStream, database: &str, word: &str) -> Result<(), Box<dyn Error>> {
+        let Some(dictionaries) = self.select(database) else {
+            return write_line(out, "550 invalid database, use \"SHOW DB\" for list of databases");
+        };
+
+        let mut definitions = Vec::new();
+        for dict in dictionaries {
+            let terms = dict.resolve(word);
+            let found = !terms.is_empty();
+            for term in terms {
+                definitions.push((dict, term));
+            }
+            if found && database == "!" {
+                break;
+            }
+        }
+
+        if definitions.is_empty() {
+            return write_line(out, "552 no match");
+        }
+
+        write_line(out, &format!("150 {} definitions retrieved", definitions.len()))?;
+        for (dict, term) in definitions {
+            write_line(out, &format!("151 {} {} {}", quote(term.headword()), dict.name(), quote(dict.title())))?;
+            self.write_text(out, &term.to_plain_text(dict.ui_language()))?;
+        }
+        write_line(out, "250 ok")
+    }
+
+    fn match_word(&self, out: &mut TcpStream, database: &str, strategy: &str, word: &str) -> Result<(), Box<dyn Error>> {
+        let Some(dictionaries) = self.select(database) else {
+            return write_line(out, "550 invalid database, use \"SHOW DB\" for list of databases");
+        };
+
+        let strategy = match strategy {
+            "." => SearchStrategy::Exact,
+            name => match STRATEGIES.iter().find(|(n, _, _)| *n == name) {
+                Some((_, _, strategy)) => *strategy,
+                None => return write_line(out, "551 invalid strategy, use \"SHOW STRAT\" for a list of strategies"),
+            },
+        };
+
+        let mut matches = Vec::new();
+        for dict in dictionaries {
+            let terms = match strategy {
+                SearchStrategy::Exact => dict.resolve(word),
+                _ => dict.search(word, strategy),
+            };
+            let found = !terms.is_empty();
+            for term in terms {
+                matches.push(format!("{} {}", dict.name(), quote(term.headword())));
+            }
+            if found && database == "!" {
+                break;
+            }
+        }
+
+        if matches.is_empty() {
+            return write_line(out, "552 no match");
+        }
+
+        write_line(out, &format!("152 {} matches found", matches.len()))?;
+        write_lines(out, &matches)?;
+        write_line(out, "250 ok")
+    }
+
+    fn show_databases(&self, out: &mut TcpStream) -> Result<(), Box<dyn Error>> {
+        if self.dictionaries.is_empty() {
+            return write_line(out, "554 no databases present");
+        }
+
+        write_line(out, &format!("110 {} databases present", self.dictionaries.len()))?;
+        let lines = self.dictionaries
+            .iter()
+            .map(|dict| format!("{} {}", dict.name(), quote(dict.title())))
+            .collect::<Vec<_>>();
+        write_lines(out, &lines)?;
+        write_line(out, "250 ok")
+    }
+
+    fn show_strategies(&self, out: &mut TcpStream) -> Result<(), Box<dyn Error>> {
+        write_line(out, &format!("111 {} strategies available", STRATEGIES.len()))?;
+        let lines = STRATEGIES
+            .iter()
+            .map(|(name, description, _)| format!("{} {}", name, quote(description)))
+            .collect::<Vec<_>>();
+        write_lines(out, &lines)?;
+        write_line(out, "250 ok")
+    }
+
+    fn show_info(&self, out: &mut TcpStream, database: &str) -> Result<(), Box<dyn Error>> {
+        let Some(dict) = self.dictionaries.iter().find(|dict| dict.name() == database) else {
+            return write_line(out, "550 invalid database, use \"SHOW DB\" for list of databases");
+        };
+
+        write_line(out, "112 database information follows")?;
+        self.write_text(out, &dict.info())?;
+        write_line(out, "250 ok")
+    }
+
+    fn show_server(&self, out: &mut TcpStream) -> Result<(), Box<dyn Error>> {
+        let mut info = format!("dictionary {}\n\n", env!("CARGO_PKG_VERSION"));
+        for dict in self.dictionaries {
+            info.push_str(format!("{}\t{} records\n", dict.name(), dict.non_empty_len()).as_str());
+        }
+
+        write_line(out, "114 server information follows")?;
+        self.write_text(out, &info)?;
+        write_line(out, "250 ok")
+    }
+
+    // "*" and "!" select all databases, otherwise the database is looked up by its name
+    fn select(&self, database: &str) -> Option<Vec<&'a Dictionary>> {
+        match database {
+            "*" | "!" => Some(self.dictionaries.iter().collect()),
+            name => self.dictionaries
+                .iter()
+                .find(|dict| dict.name() == name)
+                .map(|dict| vec![dict]),
+        }
+    }
+
+    fn write_text(&self, out: &mut TcpStream, text: &str) -> Result<(), Box<dyn Error>> {
+        let mut lines = Vec::new();
+        if self.mime {
+            lines.push("Content-Type: text/plain; charset=utf-8".to_string());
+            lines.push("".to_string());
+        }
+
+        for line in text.lines() {
+            // lines starting with period are escaped by doubling it
+            if line.starts_with('.') {
+                lines.push(format!(".{}", line));
+            } else {
+                lines.push(line.to_string());
+            }
+        }
+
+        write_lines(out, &lines)
+    }
+}
+
+// write lines followed by a line with single period
+fn write_lines(out: &mut TcpStream, lines: &[String]) -> Result<(), Box<dyn Error>> {
+    for line in lines {
+        write_line(out, line)?;
+    }
+    write_line(out, ".")
+}
+
+fn write_line(out: &mut TcpStream, line: &str) -> Result<(), Box<dyn Error>> {
+    out.write_all(format!("{}\r\n", line).as_bytes())?;
+    Ok(())
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\\\""))
+}
+
+// split the command line into words, words can be enclosed in single or double quotes
+fn split_command(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote_char = None;
+    let mut chars = line.trim().chars();
+
+    while let Some(c) = chars.next() {
+        match (quote_char, c) {
+            (Some(q), c) if c == q => quote_char = None,
+            (_, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                    in_word = true;
+                }
+            },
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote_char = Some(c);
+                in_word = true;
+            },
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    args.push(current);
+                    current = String::new();
+                    in_word = false;
+                }
+            },
+            (None, c) => {
+                current.push(c);
+                in_word = true;
+            },
+        }
+    }
+
+    if in_word {
+        args.push(current);
+    }
+
+    args
+}
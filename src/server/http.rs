@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+
+use crate::dictionary::writer::{escape_xml, json};
+use crate::dictionary::{Dictionary, SearchStrategy, Term};
+
+const DEFAULT_LIMIT: usize = 20;
+
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn json(status: &'static str, body: String) -> Response {
+        Response {
+            status,
+            content_type: "application/json; charset=utf-8",
+            body,
+        }
+    }
+
+    fn html(body: String) -> Response {
+        Response {
+            status: "200 OK",
+            content_type: "text/html; charset=utf-8",
+            body,
+        }
+    }
+
+    fn error(status: &'static str, message: &str) -> Response {
+        Response::json(status, format!("{{\"error\":{}}}", json::quote(message)))
+    }
+}
+
+// HTTP server answering lookups from given dictionaries with JSON responses
+pub fn serve(address: &str, dictionaries: Vec<Dictionary>) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(address)?;
+    let dictionaries = Arc::new(dictionaries);
+
+    println!("HTTP server listening on: http://{}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("Connection failed: {err}");
+                continue;
+            }
+        };
+
+        let dictionaries = Arc::clone(&dictionaries);
+        thread::spawn(move || {
+            if let Err(err) = handle(stream, &dictionaries) {
+                eprintln!("Connection error: {err}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle(stream: TcpStream, dictionaries: &[Dictionary]) -> Result<(), Box<dyn Error>> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut out = stream;
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // skip headers, the body is never used
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+
+    let parts = request_line.split_whitespace().collect::<Vec<_>>();
+    let response = if parts.len() < 2 {
+        Response::error("400 Bad Request", "Malformed request.")
+    } else if parts[0] != "GET" {
+        Response::error("405 Method Not Allowed", "Only GET requests are supported.")
+    } else {
+        route(parts[1], dictionaries)
+    };
+
+    write_response(&mut out, &response)
+}
+
+fn route(target: &str, dictionaries: &[Dictionary]) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let params = parse_query(query);
+
+    if path == "/" || path == "/databases" {
+        return databases(dictionaries);
+    }
+
+    let Some(dict) = select(dictionaries, params.get("db")) else {
+        return Response::error("404 Not Found", "Unknown database.");
+    };
+
+    let Some(word) = params.get("q").filter(|word| !word.is_empty()) else {
+        return Response::error("400 Bad Request", "Missing query parameter q.");
+    };
+
+    match path {
+        "/lookup" => terms_response(dict, word, dict.search(word, SearchStrategy::Exact)),
+        "/inflection" => terms_response(dict, word, dict.lookup_inflection(word)),
+        "/prefix" => {
+            let limit = params
+                .get("limit")
+                .and_then(|limit| limit.parse::<usize>().ok())
+                .unwrap_or(DEFAULT_LIMIT);
+            let terms = dict.search(word, SearchStrategy::Prefix);
+            let headwords = terms.iter().take(limit).map(|term| term.headword()).collect::<Vec<_>>();
+            Response::json("200 OK", format!(
+                "{{\"database\":{},\"query\":{},\"total\":{},\"headwords\":{}}}",
+                json::quote(&dict.name()),
+                json::quote(word),
+                terms.len(),
+                json::format_list(&headwords),
+            ))
+        },
+        "/html" => {
+            let terms = dict.search(word, SearchStrategy::Exact);
+            if terms.is_empty() {
+                return Response::error("404 Not Found", "No entry found.");
+            }
+            let entries = terms.iter().map(|term| term.to_kindle_html(dict.ui_language(), dict.entry_layout())).collect::<String>();
+            Response::html(format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\" />\n<title>{}</title>\n</head>\n<body>{}</body>\n</html>\n",
+                escape_xml(word),
+                entries,
+            ))
+        },
+        _ => Response::error("404 Not Found", "Unknown endpoint."),
+    }
+}
+
+fn databases(dictionaries: &[Dictionary]) -> Response {
+    let databases = dictionaries
+        .iter()
+        .map(|dict| format!(
+            "{{\"name\":{},\"title\":{},\"records\":{}}}",
+            json::quote(&dict.name()),
+            json::quote(dict.title()),
+            dict.non_empty_len(),
+        ))
+        .collect::<Vec<_>>();
+    Response::json("200 OK", format!("{{\"databases\":[{}]}}", databases.join(",")))
+}
+
+fn terms_response(dict: &Dictionary, word: &str, terms: Vec<&Term>) -> Response {
+    let status = if terms.is_empty() { "404 Not Found" } else { "200 OK" };
+    let terms = terms.iter().map(|term| term.to_json()).collect::<Vec<_>>();
+    Response::json(status, format!(
+        "{{\"database\":{},\"query\":{},\"terms\":[{}]}}",
+        json::quote(&dict.name()),
+        json::quote(word),
+        terms.join(","),
+    ))
+}
+
+// the first dictionary is used when no database is specified
+fn select<'a>(dictionaries: &'a [Dictionary], name: Option<&String>) -> Option<&'a Dictionary> {
+    match name {
+        Some(name) => dictionaries.iter().find(|dict| dict.name() == *name),
+        None => dictionaries.first(),
+    }
+}
+
+fn write_response(out: &mut TcpStream, response: &Response) -> Result<(), Box<dyn Error>> {
+    out.write_all(format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body,
+    ).as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(name), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match byte {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    },
+                    None => out.push(b'%'),
+                }
+            },
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}